@@ -75,7 +75,7 @@ impl Component for Counter<'_> {
             let count_text = bumpalo::format!(in arena, "Count: {:.2}", self.count);
 
             // We can nest another component inside this one.
-            let slider = Slider { value: &mut state.increase_step, min: 0.0, max: 10.0, clamp: true };
+            let slider = Slider::new(&mut state.increase_step, 0.0, 10.0, true);
 
             ui.add(panel).nest(|| {
                 ui.add(V_STACK).nest(|| {
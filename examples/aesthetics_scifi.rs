@@ -22,16 +22,33 @@ struct State {
     start: Instant,
 }
 
+#[derive(Default)]
 struct ButtonState {
-    last_click: Option<Instant>,
-    click_pos: (f32, f32),
+    // The position of the last click, while its ripple is still going.
+    ripple: Option<(f32, f32)>,
+    n_clicks: u32,
 }
 
-impl Default for ButtonState {
-    fn default() -> Self {
-        Self { last_click: None, click_pos: (0.5, 0.5) }
-    }
-}
+// The ripple grows, fades out, and blinks twice along the way.
+const RIPPLE_SIZE: f32 = 570.0;
+const RIPPLE_SCALE: &[Keyframe] = &[
+    Keyframe::new(0.0, 20.0 / RIPPLE_SIZE),
+    Keyframe::new(0.25, 1.0),
+];
+const RIPPLE_ALPHA: &[Keyframe] = &[
+    Keyframe::new(0.0, 0.85),
+    Keyframe::new(0.065, 0.63),
+    Keyframe::new(0.07, 0.0).easing(Easing::Hold),
+    Keyframe::new(0.085, 0.6).easing(Easing::Hold),
+    Keyframe::new(0.14, 0.4),
+    Keyframe::new(0.145, 0.0).easing(Easing::Hold),
+    Keyframe::new(0.16, 0.38).easing(Easing::Hold),
+    Keyframe::new(0.25, 0.0),
+];
+const RIPPLE_TRACKS: &[KeyframeTrack] = &[
+    KeyframeTrack::new(KeyframeProperty::Scale, RIPPLE_SCALE),
+    KeyframeTrack::new(KeyframeProperty::Alpha, RIPPLE_ALPHA),
+];
 
 struct Button<'a> {
     text: &'a str,
@@ -52,6 +69,7 @@ impl<'a> Button<'a> {
     }
 
     #[node_key] const CLICK_AREA: NodeKey;
+    #[node_key] const RIPPLE: NodeKey;
 }
 
 impl<'a> Component for Button<'a> {
@@ -65,14 +83,15 @@ impl<'a> Component for Button<'a> {
 
     fn add_to_ui(&mut self, ui: &mut Ui, state: &mut ButtonState) -> Self::AddResult {
 
+        // Each click gets a new ripple node, so that its keyframes start from the beginning.
+        if ui.animation_finished(Self::RIPPLE.sibling(state.n_clicks)) {
+            state.ripple = None;
+        }
         if let Some(click) = ui.clicked_at(Self::CLICK_AREA) {
-            state.last_click = Some(click.timestamp);
-            state.click_pos = (click.relative_position.x, click.relative_position.y);
+            state.n_clicks += 1;
+            state.ripple = Some((click.relative_position.x, click.relative_position.y));
         }
-
-        let click_t = state.last_click.map(|t| {
-            (t.elapsed().as_secs_f32() / 0.25).min(1.0)
-        });
+        let ripple_key = Self::RIPPLE.sibling(state.n_clicks);
 
         let hovered = ui.is_hovered(Self::CLICK_AREA) || ui.is_focused(Self::CLICK_AREA);
 
@@ -84,23 +103,15 @@ impl<'a> Component for Button<'a> {
             Size::Pixels(-10.0)
         };
 
-        let click_ripple = click_t.filter(|&t| t < 1.0).map(|t| {
-            let blink = |center: f32| (-(( t - center) / 0.04).powi(2)).exp();
-            let base_alpha = (1.0 - t) * 0.85;
-            let ripple_alpha = (base_alpha - blink(0.3) - blink(0.6)).max(0.0);
-            let ripple_size = 20.0 + t * 550.0;
+        let click_ripple = state.ripple.map(|(x, y)| {
             PANEL
-                .color(Color::from_hex_str("#ffccaa").with_alpha(ripple_alpha))
+                .color(Color::from_hex_str("#ffccaa"))
                 .anchor_symm(Anchor::Center)
-                .position_x(Pos::Frac(state.click_pos.0))
-                .position_y(Pos::Frac(state.click_pos.1))
+                .position_x(Pos::Frac(x))
+                .position_y(Pos::Frac(y))
                 .absorbs_clicks(false)
-                // Since this animation is driven manually by our update function, we need to mark this node as "sensitive to the passage of time".
-                // With this setting turned on, the Ui will know that as long as this node is visible, it will have to keep rerunning the update function on every frame, and prevent the window loop from going idle.
-                // (Actually, the Ui doesn't directly control the window loop. This setting will just cause [Ui::should_request_redraw()] to return `true`),
-                // and it's up to us or to the `run_example_loop` helper to only call `winit::window::Window::request_redraw()` only when it's true.)
-                // (...actually, in this example, the 3D wireframe will keep the loop awake either way. See the `loop_control.rs` example to see this in action.)
-                .sense_time(true)
+                // The keyframes are played by the Ui, so the update function doesn't need to run on every frame while the ripple is going.
+                .keyframes(RIPPLE_TRACKS)
                 .shape(Shape::Circle)
                 .static_image(include_bytes!("assets/noise.jpg"))
                 .image_options(ImageOptions {
@@ -108,7 +119,8 @@ impl<'a> Component for Button<'a> {
                     tile_x: TileMode::Tile,
                     tile_y: TileMode::Tile,
                 })
-                .size_symm(Size::Pixels(ripple_size))
+                .size_symm(Size::Pixels(RIPPLE_SIZE))
+                .key(ripple_key)
         });
 
         let circle = DEFAULT
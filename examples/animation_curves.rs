@@ -0,0 +1,59 @@
+//! Example comparing the different [AnimationCurve]s.
+//!
+//! Click "Move" to send all the boxes to the other side.
+
+use keru::*;
+use keru::node_library::*;
+use keru::example_window_loop::*;
+
+#[derive(Default)]
+struct State {
+    right: bool,
+}
+
+const CURVES: [(&str, AnimationCurve); 6] = [
+    ("Exponential", AnimationCurve::Exponential),
+    ("Linear", AnimationCurve::Linear { duration: 0.6 }),
+    ("Ease in-out", AnimationCurve::EaseInOutCubic { duration: 0.6 }),
+    ("Bezier", AnimationCurve::CubicBezier { x1: 0.7, y1: -0.4, x2: 0.3, y2: 1.4, duration: 0.8 }),
+    ("Spring", AnimationCurve::Spring { stiffness: 120.0, damping: 2.0 * 10.954 }),
+    ("Bouncy spring", AnimationCurve::Spring { stiffness: 200.0, damping: 8.0 }),
+];
+
+fn update_ui(state: &mut State, ui: &mut Ui) {
+    #[node_key] const MOVE: NodeKey;
+    #[node_key] const BOX: NodeKey;
+
+    if ui.is_clicked(MOVE) {
+        state.right = ! state.right;
+    }
+
+    let position = if state.right { Pos::End } else { Pos::Start };
+
+    ui.add(V_STACK.size_x(Size::Pixels(600.0)).stack_spacing(10.0)).nest(|| {
+        ui.add(BUTTON.static_text("Move").key(MOVE));
+
+        for (i, (name, curve)) in CURVES.iter().enumerate() {
+            let track = PANEL
+                .size_x(Size::Fill)
+                .size_y(Size::Pixels(50.0))
+                .fill(ui.theme().muted_background);
+
+            let moving_box = BUTTON
+                .static_text(name)
+                .position_x(position)
+                .animate_position(true)
+                .animation_curve(*curve)
+                .key(BOX.sibling(i));
+
+            ui.add(track).nest(|| {
+                ui.add(moving_box);
+            });
+        }
+    });
+}
+
+fn main() {
+    basic_env_logger_init();
+    run_example_loop(State::default(), update_ui);
+}
@@ -16,6 +16,7 @@ fn update_ui(state: &mut State, ui: &mut Ui) {
     #[node_key] const ELEM_VSTACK: NodeKey;
     #[node_key] const SUB_ELEM_VSTACK: NodeKey;
     #[node_key] const HGROUP: NodeKey;
+    #[node_key] const REDUCED_MOTION: NodeKey;
     
     let left_bar = V_STACK
         .size_x(Size::Pixels(500.0))
@@ -36,6 +37,12 @@ fn update_ui(state: &mut State, ui: &mut Ui) {
         .position_y(Pos::Start)
         .size_x(Size::FitContent);
 
+    let reduced_motion_text = if ui.reduced_motion() { "Reduced motion: on" } else { "Reduced motion: off" };
+    let reduced_motion = BUTTON
+        .static_text(reduced_motion_text)
+        .size_x(Size::FitContent)
+        .key(REDUCED_MOTION);
+
     let sub_expand = BUTTON
         .text("Sub-Expand")
         .position_x(Pos::Start)
@@ -49,6 +56,7 @@ fn update_ui(state: &mut State, ui: &mut Ui) {
     let elem_vstack = V_STACK
         .slide_from_top()
         .animate_position(true)
+        .stagger_children(0.05)
         .key(ELEM_VSTACK);
 
     let sub_elem_vstack = V_STACK
@@ -61,6 +69,7 @@ fn update_ui(state: &mut State, ui: &mut Ui) {
     let p = 4;
     
     ui.add(left_bar).nest(|| {
+        ui.add(reduced_motion);
         for i in 0..n {
             ui.add(h_group).nest(|| {
                 let expand = expand.key(EXPAND.s(i));
@@ -90,6 +99,11 @@ fn update_ui(state: &mut State, ui: &mut Ui) {
         }
     });
     
+    if ui.is_clicked(REDUCED_MOTION) {
+        let reduced_motion = ui.reduced_motion();
+        ui.set_reduced_motion(! reduced_motion);
+    }
+
     for i in 0..n {
         if ui.is_clicked(EXPAND.sibling(i)) {
             state.expanded[i] = !state.expanded[i];
@@ -0,0 +1,43 @@
+//! Example using the [Collapsible] and [Accordion] [Component]s.
+//!
+//! The sections remember whether they're open in their component state, so the State struct doesn't need to track it.
+
+use keru::*;
+use keru::node_library::*;
+use keru::example_window_loop::*;
+
+#[derive(Default)]
+struct State {
+    volume: f32,
+}
+
+fn update_ui(state: &mut State, ui: &mut Ui) {
+    ui.add(V_STACK.size_x(Size::Pixels(400.0)).stack_spacing(20.0)).nest(|| {
+        if let Some(body) = ui.add_component(Collapsible::new("Details").default_open(true)) {
+            body.nest(|| {
+                ui.static_label("This section starts open.");
+                ui.add_component(Slider::new(&mut state.volume, 0.0, 1.0, true));
+            });
+        }
+
+        if let Some(body) = ui.add_component(Collapsible::new("More details")) {
+            body.nest(|| {
+                ui.static_label("This one starts closed.");
+            });
+        }
+
+        let titles = ["General", "Audio", "Video"];
+        ui.add_component(Accordion::new(&titles, |ui, section| {
+            match section {
+                0 => { ui.static_label("General settings"); },
+                1 => { ui.static_label("Audio settings"); },
+                _ => { ui.static_label("Video settings"); },
+            }
+        }));
+    });
+}
+
+fn main() {
+    basic_env_logger_init();
+    run_example_loop(State::default(), update_ui);
+}
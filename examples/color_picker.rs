@@ -0,0 +1,44 @@
+//! Example using a [ColorPicker] [Component].
+//!
+//! Drag in the square and the bars, type a hex or RGB value, or click a swatch. The colors picked recently are shown below the palette.
+
+use keru::*;
+use keru::node_library::*;
+use keru::example_window_loop::*;
+
+struct State {
+    color: Color,
+    mode: ColorPickerMode,
+}
+
+const PALETTE: [Color; 6] = [Color::KERU_BLUE, Color::KERU_RED, Color::KERU_GREEN, Color::KERU_PINK, Color::WHITE, Color::BLACK];
+
+fn update_ui(state: &mut State, ui: &mut Ui) {
+    #[node_key] const MODE: NodeKey;
+
+    if ui.is_clicked(MODE) {
+        state.mode = match state.mode {
+            ColorPickerMode::Hsv => ColorPickerMode::OkLch,
+            ColorPickerMode::OkLch => ColorPickerMode::Hsv,
+        };
+    }
+
+    let mode_text = match state.mode {
+        ColorPickerMode::Hsv => "HSV",
+        ColorPickerMode::OkLch => "OkLCH",
+    };
+
+    ui.add(H_STACK.stack_spacing(20.0)).nest(|| {
+        ui.add(V_STACK).nest(|| {
+            ui.add(BUTTON.static_text(mode_text).key(MODE));
+            ui.add_component(ColorPicker::new(&mut state.color).mode(state.mode).palette(&PALETTE));
+        });
+        ui.add(PANEL.size_symm(Size::Pixels(150.0)).color(state.color));
+    });
+}
+
+fn main() {
+    basic_env_logger_init();
+    let state = State { color: Color::KERU_BLUE, mode: ColorPickerMode::Hsv };
+    run_example_loop(state, update_ui);
+}
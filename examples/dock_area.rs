@@ -0,0 +1,79 @@
+//! Example using a [DockArea] [Component].
+//!
+//! Drag the tabs onto another tab bar to move them, or onto the sides of a pane to split it. Drag the borders between panes to resize them.
+
+use keru::*;
+use keru::node_library::*;
+use keru::example_window_loop::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Panel {
+    Scene,
+    Inspector,
+    Console,
+    Assets,
+    Notes(u32),
+}
+
+struct Panels {
+    next_note: u32,
+}
+
+impl DockTabViewer for Panels {
+    type Tab = Panel;
+
+    fn title(&mut self, tab: Panel) -> &str {
+        match tab {
+            Panel::Scene => "Scene",
+            Panel::Inspector => "Inspector",
+            Panel::Console => "Console",
+            Panel::Assets => "Assets",
+            Panel::Notes(_) => "Notes",
+        }
+    }
+
+    fn add_content(&mut self, ui: &mut Ui, tab: Panel) {
+        match tab {
+            Panel::Notes(n) => { ui.label(&format!("Notes #{n}")); },
+            _ => { ui.label(self.title(tab)); },
+        }
+    }
+
+    fn closable(&mut self, tab: Panel) -> bool {
+        matches!(tab, Panel::Notes(_))
+    }
+}
+
+struct State {
+    layout: DockLayout<Panel>,
+    panels: Panels,
+}
+
+fn update_ui(state: &mut State, ui: &mut Ui) {
+    #[node_key] const NEW_NOTE: NodeKey;
+
+    if ui.is_clicked(NEW_NOTE) {
+        let note = Panel::Notes(state.panels.next_note);
+        state.panels.next_note += 1;
+        let group = state.layout.find_tab(Panel::Scene).or(state.layout.groups().next()).unwrap();
+        state.layout.add_tab(group, note);
+    }
+
+    ui.add(V_STACK.size_symm(Size::Fill)).nest(|| {
+        ui.add(BUTTON.static_text("New note").key(NEW_NOTE));
+        ui.add_component(DockArea::new(&mut state.layout, &mut state.panels));
+    });
+}
+
+fn main() {
+    basic_env_logger_init();
+
+    let mut layout = DockLayout::new([Panel::Scene, Panel::Notes(0)]);
+    let scene = layout.find_tab(Panel::Scene).unwrap();
+    let right = layout.split(scene, DockSide::Right, Panel::Inspector);
+    layout.split(right, DockSide::Bottom, Panel::Assets);
+    layout.split(scene, DockSide::Bottom, Panel::Console);
+
+    let state = State { layout, panels: Panels { next_note: 1 } };
+    run_example_loop(state, update_ui);
+}
@@ -31,6 +31,9 @@ impl State {
             .color(Color::GREY)
             .animation(Animation {
                 speed: 1.0,
+                curve: AnimationCurve::Exponential,
+                stagger: 0.0,
+                subtree_speed: 1.0,
                 enter: EnterAnimation::Slide { edge: SlideEdge::Top, direction: SlideDirection::In },
                 exit: ExitAnimation::None,
                 state_transition: StateTransition {
@@ -0,0 +1,49 @@
+//! Example using a [Modal] [Component].
+//!
+//! While the dialog is open, the rest of the Ui is dimmed and can't be clicked, and Tab only cycles between the buttons inside the dialog.
+//! Escape or a click on the backdrop closes it, and the focus goes back to the button that opened it.
+
+use keru::*;
+use keru::node_library::*;
+use keru::example_window_loop::*;
+
+#[derive(Default)]
+struct State {
+    dialog_open: bool,
+    count: i32,
+}
+
+fn update_ui(state: &mut State, ui: &mut Ui) {
+    #[node_key] const OPEN: NodeKey;
+    #[node_key] const INCREASE: NodeKey;
+    #[node_key] const CLOSE: NodeKey;
+
+    if ui.is_clicked(OPEN) {
+        state.dialog_open = true;
+    }
+    if ui.is_clicked(INCREASE) {
+        state.count += 1;
+    }
+    if ui.is_clicked(CLOSE) {
+        state.dialog_open = false;
+    }
+
+    ui.add(V_STACK).nest(|| {
+        ui.add(BUTTON.static_text("Open dialog").key(OPEN));
+        ui.add(BUTTON.static_text("Some other button"));
+        ui.add(LABEL.text(&format!("Count: {}", state.count)));
+    });
+
+    if let Some(dialog) = ui.add_component(Modal::new(&mut state.dialog_open)) {
+        dialog.nest(|| {
+            ui.static_label("A modal dialog");
+            ui.add(BUTTON.static_text("Increase").key(INCREASE));
+            ui.add(BUTTON.static_text("Close").key(CLOSE));
+        });
+    }
+}
+
+fn main() {
+    basic_env_logger_init();
+    run_example_loop(State::default(), update_ui);
+}
@@ -0,0 +1,48 @@
+//! Example using the [ProgressBar] and [Spinner] [Component]s.
+//!
+//! The indeterminate bar and the spinner only keep the Ui updating while they're on screen.
+//! The [AsyncButton] reports the progress of its background job in a progress bar.
+
+use std::task::Poll;
+use std::thread;
+use std::time::Duration;
+
+use keru::*;
+use keru::node_library::*;
+use keru::example_window_loop::*;
+use keru::thread_future_2::Progress;
+
+#[derive(Default)]
+struct State {
+    slider_value: f32,
+    jobs_done: u32,
+}
+
+fn update_ui(state: &mut State, ui: &mut Ui) {
+    let slow_job = |progress: &Progress| {
+        for i in 0..50 {
+            thread::sleep(Duration::from_millis(60));
+            progress.set((i + 1) as f32 / 50.0);
+        }
+    };
+
+    ui.add(V_STACK.stack_spacing(15.0)).nest(|| {
+        ui.add_component(Slider::new(&mut state.slider_value, 0.0, 1.0, true));
+        ui.add_component(ProgressBar::new(state.slider_value));
+
+        ui.static_label("Indeterminate");
+        ui.add_component(ProgressBar::indeterminate());
+        ui.add_component(Spinner::new());
+
+        let result = ui.add_component(AsyncButton::with_progress(slow_job, "Start a slow job", "Working..."));
+        if let Poll::Ready(()) = result {
+            state.jobs_done += 1;
+        }
+        ui.label(&format!("Jobs done: {}", state.jobs_done));
+    });
+}
+
+fn main() {
+    basic_env_logger_init();
+    run_example_loop(State::default(), update_ui);
+}
@@ -0,0 +1,47 @@
+//! Example of property animations. Hover the cards to see their corner radius, stroke, shadow and text animate.
+
+use keru::*;
+use keru::node_library::*;
+use keru::example_window_loop::*;
+
+fn update_ui(_state: &mut (), ui: &mut Ui) {
+    #[node_key] const CARD: NodeKey;
+
+    ui.add(H_STACK.stack_spacing(30.0)).nest(|| {
+        for i in 0..3 {
+            let key = CARD.sibling(i);
+            let hovered = ui.is_hovered(key);
+
+            let card = PANEL
+                .size_symm(Size::Pixels(180.0))
+                .sense_hover(true)
+                .animate_properties(true)
+                .key(key);
+
+            let card = if hovered {
+                card
+                    .shape(Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 40.0 })
+                    .stroke(4.0)
+                    .stroke_color(Color::KERU_PINK)
+                    .shadow(Shadow { blur: 30.0, offset: Xy::new(0.0, 12.0), color: None })
+                    .text_size(34.0)
+                    .text_color(Color::KERU_PINK)
+            } else {
+                card
+                    .shape(Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 6.0 })
+                    .stroke(1.0)
+                    .stroke_color(Color::GREY)
+                    .shadow(Shadow { blur: 4.0, offset: Xy::new(0.0, 2.0), color: None })
+                    .text_size(22.0)
+                    .text_color(Color::WHITE)
+            };
+
+            ui.add(card.static_text("Hover me"));
+        }
+    });
+}
+
+fn main() {
+    basic_env_logger_init();
+    run_example_loop((), update_ui);
+}
@@ -0,0 +1,26 @@
+//! Example using a [RangeSlider] [Component].
+
+use keru::*;
+use keru::node_library::*;
+use keru::example_window_loop::*;
+
+struct State {
+    price: (f32, f32),
+    hours: (f32, f32),
+}
+
+fn update_ui(state: &mut State, ui: &mut Ui) {
+    ui.add(V_STACK.size_x(Size::Pixels(400.0))).nest(|| {
+        ui.label(&format!("Price: {:.0} - {:.0}", state.price.0, state.price.1));
+        ui.add_component(RangeSlider::new(&mut state.price, 0.0, 500.0).step(10.0).ticks(50.0));
+
+        ui.label(&format!("Hours: {:.1} - {:.1}", state.hours.0, state.hours.1));
+        ui.add_component(RangeSlider::new(&mut state.hours, 0.0, 24.0));
+    });
+}
+
+fn main() {
+    basic_env_logger_init();
+    let state = State { price: (100.0, 300.0), hours: (9.0, 17.5) };
+    run_example_loop(state, update_ui);
+}
@@ -0,0 +1,77 @@
+//! Example of a shared-element transition.
+//!
+//! Click a card to move it from the grid into the detail panel. Since it's added with the same key, it animates from its old place to the new one.
+
+use keru::*;
+use keru::node_library::*;
+use keru::example_window_loop::*;
+
+#[derive(Default)]
+struct State {
+    selected: Option<usize>,
+}
+
+const NAMES: [&str; 6] = ["Card 0", "Card 1", "Card 2", "Card 3", "Card 4", "Card 5"];
+const COLORS: [Color; 3] = [Color::KERU_BLUE, Color::KERU_PINK, Color::KERU_GREEN];
+
+fn update_ui(state: &mut State, ui: &mut Ui) {
+    #[node_key] const CARD: NodeKey;
+    #[node_key] const CLOSE: NodeKey;
+
+    for i in 0..NAMES.len() {
+        if ui.is_clicked(CARD.sibling(i)) {
+            state.selected = if state.selected == Some(i) { None } else { Some(i) };
+        }
+    }
+    if ui.is_clicked(CLOSE) {
+        state.selected = None;
+    }
+
+    let card = |i: usize| {
+        BUTTON
+            .size_symm(Size::Pixels(120.0))
+            .color(COLORS[i % COLORS.len()])
+            .static_text(NAMES[i])
+            .animate_position(true)
+            .key(CARD.sibling(i))
+    };
+
+    let grid = H_STACK
+        .size_x(Size::Pixels(450.0))
+        .stack_spacing(10.0)
+        .position_x(Pos::Start)
+        .padding(20.0);
+
+    let detail_panel = PANEL
+        .size_x(Size::Pixels(400.0))
+        .size_y(Size::Frac(0.8))
+        .position_x(Pos::End)
+        .stack(Axis::Y, Arrange::Start, 20.0)
+        .slide_from_right();
+
+    ui.add(H_STACK.size_x(Size::Fill)).nest(|| {
+        ui.add(V_STACK.position_x(Pos::Start)).nest(|| {
+            for row in 0..2 {
+                ui.add(grid).nest(|| {
+                    for i in (row * 3)..(row * 3 + 3) {
+                        if state.selected != Some(i) {
+                            ui.add(card(i));
+                        }
+                    }
+                });
+            }
+        });
+
+        if let Some(i) = state.selected {
+            ui.add(detail_panel).nest(|| {
+                ui.add(card(i).size_symm(Size::Pixels(300.0)));
+                ui.add(BUTTON.static_text("Close").key(CLOSE));
+            });
+        }
+    });
+}
+
+fn main() {
+    basic_env_logger_init();
+    run_example_loop(State::default(), update_ui);
+}
@@ -0,0 +1,76 @@
+//! Example using a [Table] [Component].
+//!
+//! Click a header to sort, drag a header onto another one to reorder the columns, and drag the right edge of a header to resize it.
+//! Only the rows that are scrolled into view are added to the Ui.
+
+use keru::*;
+use keru::example_window_loop::*;
+
+struct Person {
+    name: String,
+    age: u32,
+    city: &'static str,
+}
+
+struct State {
+    people: Vec<Person>,
+    // Indices into `people`, in the order given by the table's sort.
+    sorted: Vec<usize>,
+    table: TableState,
+}
+
+const CITIES: [&str; 5] = ["Rome", "Oslo", "Lima", "Kyoto", "Accra"];
+
+fn update_ui(state: &mut State, ui: &mut Ui) {
+    let columns = [
+        TableColumn::new("Name", 200.0),
+        TableColumn::new("Age", 80.0),
+        TableColumn::new("City", 150.0),
+    ];
+
+    let people = &state.people;
+    let sorted = &state.sorted;
+    let response = ui.add_component(Table::new(&mut state.table, &columns, people.len(), |ui, row, column| {
+        let person = &people[sorted[row]];
+        match column {
+            0 => { ui.label(&person.name); },
+            1 => { ui.label(&person.age.to_string()); },
+            _ => { ui.static_label(person.city); },
+        }
+    }));
+
+    if response.sort_changed {
+        let people = &state.people;
+        state.sorted = (0..people.len()).collect();
+        if let Some((column, direction)) = state.table.sort {
+            state.sorted.sort_by(|&a, &b| {
+                let ordering = match column {
+                    0 => people[a].name.cmp(&people[b].name),
+                    1 => people[a].age.cmp(&people[b].age),
+                    _ => people[a].city.cmp(people[b].city),
+                };
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+    }
+}
+
+fn main() {
+    basic_env_logger_init();
+
+    let people: Vec<Person> = (0..10_000).map(|i| Person {
+        name: format!("Person {i}"),
+        age: (i * 7 % 90) as u32,
+        city: CITIES[i % CITIES.len()],
+    }).collect();
+
+    let state = State {
+        sorted: (0..people.len()).collect(),
+        people,
+        table: TableState::default(),
+    };
+    run_example_loop(state, update_ui);
+}
@@ -0,0 +1,49 @@
+//! Example using toast notifications.
+//!
+//! Toasts are dismissed after a few seconds, but the countdown is paused while the cursor is on them.
+
+use std::time::Duration;
+
+use keru::*;
+use keru::node_library::*;
+use keru::example_window_loop::*;
+
+#[derive(Default)]
+struct State {
+    saved: u32,
+    undo_toast: Option<ToastId>,
+}
+
+fn update_ui(state: &mut State, ui: &mut Ui) {
+    #[node_key] const SAVE: NodeKey;
+    #[node_key] const DELETE: NodeKey;
+    #[node_key] const FAIL: NodeKey;
+
+    if ui.is_clicked(SAVE) {
+        state.saved += 1;
+        ui.notify(&format!("Saved {} times", state.saved), ToastLevel::Success, Duration::from_secs(3));
+    }
+    if ui.is_clicked(DELETE) {
+        let id = ui.notify_with_actions("File deleted", ToastLevel::Info, Duration::from_secs(5), &["Undo"]);
+        state.undo_toast = Some(id);
+    }
+    if ui.is_clicked(FAIL) {
+        ui.notify("Couldn't connect to the server", ToastLevel::Error, Duration::MAX);
+    }
+
+    if let Some(id) = state.undo_toast && ui.toast_action_clicked(id).is_some() {
+        ui.notify("File restored", ToastLevel::Info, Duration::from_secs(3));
+        state.undo_toast = None;
+    }
+
+    ui.add(V_STACK).nest(|| {
+        ui.add(BUTTON.static_text("Save").key(SAVE));
+        ui.add(BUTTON.static_text("Delete").key(DELETE));
+        ui.add(BUTTON.static_text("Fail").key(FAIL));
+    });
+}
+
+fn main() {
+    basic_env_logger_init();
+    run_example_loop(State::default(), update_ui);
+}
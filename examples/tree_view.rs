@@ -0,0 +1,72 @@
+//! Example using a [TreeView] [Component].
+//!
+//! Click the arrows or use Left/Right to expand and collapse the folders, and drag an item onto a folder to move it there.
+//! The children are only looked up when a folder is expanded.
+
+use keru::*;
+use keru::example_window_loop::*;
+
+struct Item {
+    name: &'static str,
+    parent: Option<usize>,
+    is_folder: bool,
+}
+
+struct Files {
+    items: Vec<Item>,
+}
+
+impl TreeViewSource for Files {
+    type Id = usize;
+
+    fn children(&mut self, parent: Option<usize>, out: &mut Vec<usize>) {
+        out.extend((0..self.items.len()).filter(|&i| self.items[i].parent == parent));
+    }
+
+    fn has_children(&mut self, id: usize) -> bool {
+        self.items[id].is_folder
+    }
+
+    fn add_item(&mut self, ui: &mut Ui, id: usize) {
+        ui.static_label(self.items[id].name);
+    }
+
+    fn can_reparent(&mut self, _id: usize, new_parent: Option<usize>) -> bool {
+        new_parent.is_none_or(|p| self.items[p].is_folder)
+    }
+}
+
+struct State {
+    files: Files,
+    tree: TreeViewState<usize>,
+}
+
+fn update_ui(state: &mut State, ui: &mut Ui) {
+    let response = ui.add_component(TreeView::new(&mut state.tree, &mut state.files));
+
+    if let Some((id, new_parent)) = response.reparented {
+        state.files.items[id].parent = new_parent;
+    }
+}
+
+fn main() {
+    basic_env_logger_init();
+
+    let item = |name, parent, is_folder| Item { name, parent, is_folder };
+    let files = Files {
+        items: vec![
+            item("src", None, true),
+            item("main.rs", Some(0), false),
+            item("ui", Some(0), true),
+            item("button.rs", Some(2), false),
+            item("slider.rs", Some(2), false),
+            item("assets", None, true),
+            item("icon.png", Some(5), false),
+            item("Cargo.toml", None, false),
+            item("README.md", None, false),
+        ],
+    };
+
+    let state = State { files, tree: TreeViewState::default() };
+    run_example_loop(state, update_ui);
+}
@@ -97,6 +97,12 @@ impl Ui {
         if keru_node.params.interact.senses.contains(Sense::CLICK) {
             node.add_action(Action::Click);
         }
+        if keru_node.params.interact.focus_trap {
+            node.set_modal();
+        }
+        if keru_node.params.interact.disabled {
+            node.set_disabled();
+        }
         // Selectable roles (Tab, ListItem, ...) report their selected state.
         // We only set it when true; a selectable-role node defaults to
         // "not selected", so unselected siblings need no explicit marking.
@@ -106,8 +112,20 @@ impl Ui {
 
         if let Some(numeric_value) = self.sys.nodes[i].params.accessibility.numeric_value {
             node.set_numeric_value(numeric_value.value);
-            node.set_min_numeric_value(numeric_value.min);
-            node.set_max_numeric_value(numeric_value.max);
+            // Unbounded ranges don't report a bound at all.
+            if numeric_value.min.is_finite() {
+                node.set_min_numeric_value(numeric_value.min);
+            }
+            if numeric_value.max.is_finite() {
+                node.set_max_numeric_value(numeric_value.max);
+            }
+        }
+        if let Some(expanded) = self.sys.nodes[i].params.accessibility.expanded {
+            node.set_expanded(expanded);
+            node.add_action(if expanded { Action::Collapse } else { Action::Expand });
+        }
+        if let Some(live) = self.sys.nodes[i].params.accessibility.live {
+            node.set_live(live);
         }
         // todo: we could try to add this just for nodes that actually have a scrollable parent or grandparent, but I don't know if it's worth the trouble right now.
         if ! is_root {
@@ -173,6 +191,11 @@ impl Ui {
             return;
         };
 
+        // Disabled nodes ignore actions, just like they ignore the mouse.
+        if self.sys.nodes[i].params.interact.disabled {
+            return;
+        }
+
         self.sys.accesskit_actions.push((id, request.action));
 
         match request.action {
@@ -0,0 +1,90 @@
+use std::time::{Duration, Instant};
+
+/// Where animations get their frame time from.
+///
+/// Set it with [`Ui::set_animation_clock()`](crate::Ui::set_animation_clock).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum AnimationClock {
+    /// Measure the real time between animation steps. This is the default.
+    #[default]
+    Real,
+    /// Advance every animation step by exactly this duration, regardless of how much time actually passed.
+    ///
+    /// This makes animations deterministic, which is useful for tests and for recording videos frame by frame.
+    Fixed(Duration),
+}
+
+// The step used for the first frame of an animation, when there's no previous frame to measure from.
+const NOMINAL_DT: f32 = 1.0 / 60.0;
+// Longer frames are clamped to this, so that a stall doesn't make everything teleport to its target.
+const MAX_DT: f32 = 0.1;
+
+/// Measures the time between consecutive animation steps.
+#[derive(Debug, Default)]
+pub(crate) struct FrameTimer {
+    last_step: Option<Instant>,
+}
+
+impl FrameTimer {
+    /// Returns the time in seconds since the last step, and starts a new one.
+    pub(crate) fn tick(&mut self, clock: AnimationClock) -> f32 {
+        return self.tick_at(clock, Instant::now());
+    }
+
+    fn tick_at(&mut self, clock: AnimationClock, now: Instant) -> f32 {
+        match clock {
+            AnimationClock::Fixed(dt) => {
+                self.last_step = None;
+                return dt.as_secs_f32();
+            },
+            AnimationClock::Real => {
+                let dt = match self.last_step {
+                    Some(last_step) => now.duration_since(last_step).as_secs_f32().min(MAX_DT),
+                    None => NOMINAL_DT,
+                };
+                self.last_step = Some(now);
+                return dt;
+            },
+        }
+    }
+
+    /// Forget the last step, because nothing is animating anymore.
+    ///
+    /// Otherwise, the first step of the next animation would measure all the idle time since then.
+    pub(crate) fn stop(&mut self) {
+        self.last_step = None;
+    }
+}
+
+#[cfg(test)]
+mod test_frame_timer {
+    use super::*;
+
+    #[test]
+    fn measured_steps() {
+        let mut timer = FrameTimer::default();
+        let t0 = Instant::now();
+
+        assert_eq!(timer.tick_at(AnimationClock::Real, t0), NOMINAL_DT);
+        let dt = timer.tick_at(AnimationClock::Real, t0 + Duration::from_millis(7));
+        assert!((dt - 0.007).abs() < 1e-6);
+
+        // A stall gets clamped.
+        let dt = timer.tick_at(AnimationClock::Real, t0 + Duration::from_secs(5));
+        assert_eq!(dt, MAX_DT);
+
+        // After stopping, the idle time isn't counted.
+        timer.stop();
+        assert_eq!(timer.tick_at(AnimationClock::Real, t0 + Duration::from_secs(60)), NOMINAL_DT);
+    }
+
+    #[test]
+    fn fixed_steps() {
+        let mut timer = FrameTimer::default();
+        let t0 = Instant::now();
+        let clock = AnimationClock::Fixed(Duration::from_millis(20));
+
+        assert_eq!(timer.tick_at(clock, t0), 0.02);
+        assert_eq!(timer.tick_at(clock, t0 + Duration::from_secs(3)), 0.02);
+    }
+}
@@ -0,0 +1,302 @@
+use std::hash::{Hash, Hasher};
+
+/// How a node animates towards its target, for position transitions, enter and exit animations, fades and property transitions.
+///
+/// Set it with [`Node::animation_curve()`](crate::Node::animation_curve).
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum AnimationCurve {
+    /// Approach the target quickly at first, then slow down, with a minimum speed so that it doesn't crawl at the end. This is the default.
+    #[default]
+    Exponential,
+    /// Move at a constant speed, reaching the target after `duration` seconds.
+    Linear { duration: f32 },
+    /// Accelerate, then decelerate, reaching the target after `duration` seconds.
+    EaseInOutCubic { duration: f32 },
+    /// A CSS-style cubic bezier timing function, with control points `(x1, y1)` and `(x2, y2)`. `x1` and `x2` should be between 0.0 and 1.0.
+    CubicBezier { x1: f32, y1: f32, x2: f32, y2: f32, duration: f32 },
+    /// A spring pulling the node towards the target, with unit mass. The animation takes as long as it takes for the spring to settle.
+    ///
+    /// `damping` equal to `2.0 * stiffness.sqrt()` is critically damped: it reaches the target as fast as possible without overshooting. Lower values bounce.
+    Spring { stiffness: f32, damping: f32 },
+}
+
+impl AnimationCurve {
+    /// A critically damped spring with the given stiffness.
+    pub fn critically_damped_spring(stiffness: f32) -> AnimationCurve {
+        AnimationCurve::Spring { stiffness, damping: 2.0 * stiffness.sqrt() }
+    }
+
+    // Progress from 0.0 to 1.0 (or a bit beyond, for a bouncy spring) after `t` seconds, and whether it's finished.
+    fn progress(&self, t: f32) -> (f32, bool) {
+        let by_duration = |duration: f32, ease: &dyn Fn(f32) -> f32| {
+            if duration <= 0.0 || t >= duration {
+                return (1.0, true);
+            }
+            return (ease(t / duration), false);
+        };
+        match *self {
+            AnimationCurve::Exponential => (1.0, true),
+            AnimationCurve::Linear { duration } => by_duration(duration, &|x| x),
+            AnimationCurve::EaseInOutCubic { duration } => by_duration(duration, &ease_in_out_cubic),
+            AnimationCurve::CubicBezier { x1, y1, x2, y2, duration } => by_duration(duration, &|x| cubic_bezier(x1, y1, x2, y2, x)),
+            AnimationCurve::Spring { stiffness, damping } => spring(stiffness, damping, t),
+        }
+    }
+}
+
+impl Hash for AnimationCurve {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match *self {
+            AnimationCurve::Exponential => {},
+            AnimationCurve::Linear { duration } => duration.to_bits().hash(state),
+            AnimationCurve::EaseInOutCubic { duration } => duration.to_bits().hash(state),
+            AnimationCurve::CubicBezier { x1, y1, x2, y2, duration } => {
+                for v in [x1, y1, x2, y2, duration] {
+                    v.to_bits().hash(state);
+                }
+            },
+            AnimationCurve::Spring { stiffness, damping } => {
+                stiffness.to_bits().hash(state);
+                damping.to_bits().hash(state);
+            },
+        }
+    }
+}
+
+pub(crate) fn ease_in_out_cubic(x: f32) -> f32 {
+    if x < 0.5 { 4.0 * x * x * x } else { 1.0 - (-2.0 * x + 2.0).powi(3) / 2.0 }
+}
+
+// Solve x(s) = x for the curve parameter s with Newton's method, then return y(s).
+pub(crate) fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    let bezier = |a: f32, b: f32, s: f32| {
+        let u = 1.0 - s;
+        3.0 * u * u * s * a + 3.0 * u * s * s * b + s * s * s
+    };
+    let derivative = |a: f32, b: f32, s: f32| {
+        let u = 1.0 - s;
+        3.0 * u * u * a + 6.0 * u * s * (b - a) + 3.0 * s * s * (1.0 - b)
+    };
+
+    let mut s = x;
+    for _ in 0..8 {
+        let error = bezier(x1, x2, s) - x;
+        let slope = derivative(x1, x2, s);
+        if error.abs() < 1e-5 || slope.abs() < 1e-6 {
+            break;
+        }
+        s = (s - error / slope).clamp(0.0, 1.0);
+    }
+    return bezier(y1, y2, s);
+}
+
+// Closed form step response of a damped spring with unit mass, starting at rest at 0.0 and pulled towards 1.0.
+fn spring(stiffness: f32, damping: f32, t: f32) -> (f32, bool) {
+    if stiffness <= 0.0 {
+        return (1.0, true);
+    }
+    let omega = stiffness.sqrt();
+    let zeta = damping.max(0.0) / (2.0 * omega);
+
+    // How fast the slowest part of the motion decays.
+    let (progress, decay_rate) = if zeta < 0.999 {
+        let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+        let envelope = (-zeta * omega * t).exp();
+        let p = 1.0 - envelope * ((omega_d * t).cos() + zeta * omega / omega_d * (omega_d * t).sin());
+        (p, zeta * omega)
+    } else if zeta <= 1.001 {
+        (1.0 - (-omega * t).exp() * (1.0 + omega * t), omega)
+    } else {
+        let root = (zeta * zeta - 1.0).sqrt();
+        let r1 = -omega * (zeta - root);
+        let r2 = -omega * (zeta + root);
+        let p = 1.0 + (r2 * (r1 * t).exp() - r1 * (r2 * t).exp()) / (r1 - r2);
+        (p, -r1)
+    };
+
+    // e^-9 is about 1e-4. An undamped spring never settles, so it gets cut off after a while.
+    let settled = decay_rate * t > 9.0 || t > 10.0;
+    if settled {
+        return (1.0, true);
+    }
+    return (progress, false);
+}
+
+/// How far an animated value should move in a frame.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum CurveStep {
+    /// The [`AnimationCurve::Exponential`] step: cover `rate` of the remaining distance, but move at least at a minimum speed for `dt` seconds.
+    Exponential { rate: f32, dt: f32 },
+    /// Cover this fraction of the remaining distance. It can be more than 1.0 for a bouncy spring.
+    Fraction(f32),
+    /// Snap to the target.
+    Done,
+}
+
+/// The state of an animation that follows an [`AnimationCurve`].
+///
+/// Instead of remembering where the animation started, each step covers the part of the remaining distance that the curve's progress says it should.
+/// This way the same state works for rectangles, colors, shapes and anything else that can be stepped towards a target.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub(crate) struct CurveProgress {
+    elapsed: f32,
+    progress: f32,
+}
+
+impl CurveProgress {
+    pub(crate) const NEW: CurveProgress = CurveProgress { elapsed: 0.0, progress: 0.0 };
+
+    /// Start over, because the target changed.
+    pub(crate) fn restart(&mut self) {
+        *self = CurveProgress::NEW;
+    }
+
+    /// Advance by `dt` seconds, already multiplied by the animation speed.
+    ///
+    /// `exponential_rate` is the rate per second used for [`AnimationCurve::Exponential`].
+    pub(crate) fn advance(&mut self, curve: AnimationCurve, dt: f32, exponential_rate: f32) -> CurveStep {
+        // Disabled animations jump to the target.
+        if dt.is_infinite() {
+            self.restart();
+            return CurveStep::Done;
+        }
+        if curve == AnimationCurve::Exponential {
+            return CurveStep::Exponential { rate: (exponential_rate * dt).clamp(0.0, 1.0), dt };
+        }
+
+        self.elapsed += dt;
+        let (progress, finished) = curve.progress(self.elapsed);
+        if finished {
+            self.restart();
+            return CurveStep::Done;
+        }
+
+        let remaining = 1.0 - self.progress;
+        let fraction = if remaining.abs() < 1e-6 { 1.0 } else { (progress - self.progress) / remaining };
+        self.progress = progress;
+        return CurveStep::Fraction(fraction);
+    }
+}
+
+impl CurveStep {
+    /// Step a single value. Returns the new value and whether it reached the target.
+    ///
+    /// `min_speed`, in units per second, and `snap` are only used for the exponential step.
+    pub(crate) fn apply(self, current: f32, target: f32, min_speed: f32, snap: f32) -> (f32, bool) {
+        match self {
+            CurveStep::Exponential { rate, dt } => {
+                let diff = target - current;
+                let dist = diff.abs();
+                if dist < snap {
+                    (target, true)
+                } else {
+                    // exponential step at the start, but never slower than min_speed, and never overshooting.
+                    let step = (dist * rate).max(min_speed * dt).min(dist);
+                    (current + step * diff.signum(), false)
+                }
+            },
+            CurveStep::Fraction(fraction) => (current + (target - current) * fraction, false),
+            CurveStep::Done => (target, true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_animation_curve {
+    use super::*;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    #[test]
+    fn cubic_bezier_endpoints_and_linear() {
+        for (x1, y1, x2, y2) in [(0.25, 0.1, 0.25, 1.0), (0.42, 0.0, 0.58, 1.0), (0.0, 0.0, 1.0, 1.0)] {
+            assert_eq!(cubic_bezier(x1, y1, x2, y2, 0.0), 0.0);
+            assert!((cubic_bezier(x1, y1, x2, y2, 1.0) - 1.0).abs() < 1e-5);
+        }
+        // With the control points on the diagonal, the curve is the identity.
+        for x in [0.1, 0.3, 0.5, 0.8] {
+            assert!((cubic_bezier(1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0, x) - x).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn every_curve_finishes_at_one() {
+        let curves = [
+            AnimationCurve::Linear { duration: 0.3 },
+            AnimationCurve::EaseInOutCubic { duration: 0.3 },
+            AnimationCurve::CubicBezier { x1: 0.25, y1: 0.1, x2: 0.25, y2: 1.0, duration: 0.3 },
+            AnimationCurve::critically_damped_spring(100.0),
+            AnimationCurve::Spring { stiffness: 100.0, damping: 5.0 },
+            AnimationCurve::Spring { stiffness: 100.0, damping: 40.0 },
+            AnimationCurve::Spring { stiffness: 100.0, damping: 0.0 },
+        ];
+        for curve in curves {
+            let mut t = 0.0;
+            loop {
+                t += DT;
+                let (progress, finished) = curve.progress(t);
+                if finished {
+                    assert_eq!(progress, 1.0);
+                    break;
+                }
+                assert!(t < 11.0, "{curve:?} never finished");
+            }
+        }
+    }
+
+    #[test]
+    fn critically_damped_spring_doesnt_overshoot() {
+        let curve = AnimationCurve::critically_damped_spring(200.0);
+        let mut last = 0.0;
+        for n in 1..1000 {
+            let (progress, _) = curve.progress(n as f32 * 0.005);
+            assert!(progress <= 1.0);
+            assert!(progress >= last);
+            last = progress;
+        }
+    }
+
+    #[test]
+    fn underdamped_spring_overshoots() {
+        let curve = AnimationCurve::Spring { stiffness: 100.0, damping: 2.0 };
+        let overshoots = (1..200).any(|n| curve.progress(n as f32 * 0.01).0 > 1.0);
+        assert!(overshoots);
+    }
+
+    #[test]
+    fn fraction_steps_add_up_to_the_target() {
+        let curve = AnimationCurve::EaseInOutCubic { duration: 0.5 };
+        let mut progress = CurveProgress::NEW;
+        let (start, target) = (2.0, 12.0);
+        let mut value = start;
+        let mut t = 0.0;
+        loop {
+            t += DT;
+            let step = progress.advance(curve, DT, 0.0);
+            let (new_value, done) = step.apply(value, target, 0.0, 0.0);
+            value = new_value;
+            if step == CurveStep::Done {
+                assert!(done);
+                break;
+            }
+            // Stepping a fraction of the remaining distance each frame lands exactly where the curve says.
+            let expected = start + (target - start) * ease_in_out_cubic(t / 0.5);
+            assert!((value - expected).abs() < 1e-3, "at {t}: {value} instead of {expected}");
+        }
+        assert_eq!(value, target);
+        assert_eq!(progress, CurveProgress::NEW);
+    }
+
+    #[test]
+    fn disabled_and_exponential_steps() {
+        let mut progress = CurveProgress::NEW;
+        let curve = AnimationCurve::Linear { duration: 1.0 };
+        progress.advance(curve, 0.25, 5.0);
+        assert_eq!(progress.advance(curve, f32::INFINITY, 5.0), CurveStep::Done);
+        assert_eq!(progress, CurveProgress::NEW);
+
+        assert_eq!(progress.advance(AnimationCurve::Exponential, 0.1, 5.0), CurveStep::Exponential { rate: 0.5, dt: 0.1 });
+        assert_eq!(progress.advance(AnimationCurve::Exponential, 1.0, 5.0), CurveStep::Exponential { rate: 1.0, dt: 1.0 });
+    }
+}
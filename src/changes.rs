@@ -25,6 +25,8 @@ pub struct Changes {
     // todo: bitflags, or just less bools
     pub full_relayout: bool,
     pub text_changed: bool,
+    // Set by text size animations, which run after this frame's layout. Not cleared by reset_layout_changes(), so it's picked up on the next frame.
+    pub pending_text_relayout: bool,
     pub unfinished_animations: bool,
 
     pub focus_changed: bool,
@@ -42,6 +44,7 @@ impl Changes {
             partial_relayouts: Vec::with_capacity(15),
             rebuild_render_data: false,
             text_changed: false,
+            pending_text_relayout: false,
             full_relayout: true,
             unfinished_animations: false,
             focus_changed: false,
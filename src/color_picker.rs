@@ -0,0 +1,563 @@
+use crate as keru;
+use keru::*;
+use keru::node_library::*;
+
+/// The color space used by a [`ColorPicker`]'s square and hue bar.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorPickerMode {
+    /// Saturation and value at a fixed hue.
+    #[default]
+    Hsv,
+    /// Chroma and lightness at a fixed hue, in the perceptual OkLCH space.
+    ///
+    /// Colors outside of the sRGB gamut are clamped.
+    OkLch,
+}
+
+// Chroma at the right edge of the OkLCH square. A bit above the most saturated sRGB colors.
+const OKLCH_MAX_CHROMA: f32 = 0.37;
+
+const COLOR_PICKER_SIZE: f32 = 200.0;
+const COLOR_PICKER_BAR_WIDTH: f32 = 18.0;
+const COLOR_PICKER_MAX_RECENT: usize = 8;
+
+// The OkLCH square is drawn as a grid of horizontal gradients.
+const OKLCH_ROWS: usize = 16;
+const OKLCH_COLUMNS: usize = 8;
+
+#[derive(Default)]
+pub struct ColorPickerState {
+    // Hue, then the square's x and y, all from 0.0 to 1.0.
+    // They're kept separately from the color, so that the hue isn't lost when the color becomes grey or black.
+    coords: [f32; 3],
+    coords_mode: Option<ColorPickerMode>,
+    last_color: Option<[f32; 4]>,
+    // Hex, red, green and blue.
+    texts: [String; 4],
+    invalid: [bool; 4],
+    was_editing: [bool; 4],
+    recent: Vec<Color>,
+}
+
+/// A color picker with a saturation/value (or chroma/lightness) square, a hue bar, an alpha bar, hex and RGB text inputs, and a palette of swatches.
+///
+/// The colors that were picked recently are shown after the palette.
+///
+/// ```no_run
+/// # use keru::*; let mut ui: Ui = unimplemented!();
+/// # let mut color = Color::KERU_BLUE;
+/// ui.add_component(ColorPicker::new(&mut color).mode(ColorPickerMode::OkLch));
+/// ```
+pub struct ColorPicker<'a> {
+    pub color: &'a mut Color,
+    pub mode: ColorPickerMode,
+    /// Show the alpha bar and the alpha digits in the hex input.
+    pub alpha: bool,
+    pub palette: &'a [Color],
+    pub key: Option<ComponentKey<Self>>,
+}
+
+impl<'a> ColorPicker<'a> {
+    pub fn new(color: &'a mut Color) -> Self {
+        Self {
+            color,
+            mode: ColorPickerMode::Hsv,
+            alpha: true,
+            palette: &[],
+            key: None,
+        }
+    }
+
+    pub fn mode(mut self, mode: ColorPickerMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn alpha(mut self, alpha: bool) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn palette(mut self, palette: &'a [Color]) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    fn format(&self, color: Color, field: usize) -> String {
+        let [r, g, b, a] = to_u8(color);
+        match field {
+            0 if self.alpha && a != 255 => format!("#{r:02X}{g:02X}{b:02X}{a:02X}"),
+            0 => format!("#{r:02X}{g:02X}{b:02X}"),
+            1 => r.to_string(),
+            2 => g.to_string(),
+            _ => b.to_string(),
+        }
+    }
+
+    fn parse(&self, text: &str, field: usize, color: Color) -> Option<Color> {
+        let text = text.trim();
+        if field == 0 {
+            let hex = text.strip_prefix('#').unwrap_or(text);
+            if ! hex.is_ascii() {
+                return None;
+            }
+            let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+            let a = match hex.len() {
+                6 => to_u8(color)[3],
+                8 if self.alpha => byte(6)?,
+                _ => return None,
+            };
+            return Some(from_u8([byte(0)?, byte(2)?, byte(4)?, a]));
+        }
+
+        let channel = text.parse::<u8>().ok()? as f32 / 255.0;
+        let mut color = color;
+        match field {
+            1 => color.r = channel,
+            2 => color.g = channel,
+            _ => color.b = channel,
+        }
+        return Some(color);
+    }
+}
+
+impl Component for ColorPicker<'_> {
+    /// `true` if the color was changed this frame.
+    type AddResult = bool;
+    type ComponentOutput = ();
+    type State = ColorPickerState;
+
+    fn add_to_ui(&mut self, ui: &mut Ui, state: &mut Self::State) -> Self::AddResult {
+        use winit::keyboard::{Key, NamedKey};
+
+        #[node_key] const COLOR_PICKER_SQUARE: NodeKey;
+        #[node_key] const COLOR_PICKER_HUE: NodeKey;
+        #[node_key] const COLOR_PICKER_ALPHA: NodeKey;
+        #[node_key] const COLOR_PICKER_FIELD: NodeKey;
+        #[node_key] const COLOR_PICKER_SWATCH: NodeKey;
+        #[node_key] const COLOR_PICKER_RECENT: NodeKey;
+
+        let initial_color = *self.color;
+        let mut color = initial_color;
+
+        // Set when the color changed from anything other than typing, so the texts have to be regenerated.
+        let mut reformat = state.last_color != Some(to_array(color));
+        if reformat || state.coords_mode != Some(self.mode) {
+            state.coords = coords_from_color(self.mode, color, state.coords);
+            state.coords_mode = Some(self.mode);
+        }
+        let mut coords_changed = false;
+        let mut commit = false;
+
+        // Square, hue and alpha bars
+        let position = |ui: &mut Ui, key: NodeKey| {
+            let position = ui.is_dragged(key).map(|d| d.relative_position)
+                .or_else(|| ui.clicked_at(key).map(|c| c.relative_position))?;
+            return Some(position.clamp(glam::Vec2::ZERO, glam::Vec2::ONE));
+        };
+        if let Some(pos) = position(ui, COLOR_PICKER_SQUARE) {
+            state.coords[1] = pos.x;
+            state.coords[2] = 1.0 - pos.y;
+            coords_changed = true;
+        }
+        if let Some(pos) = position(ui, COLOR_PICKER_HUE) {
+            state.coords[0] = pos.y;
+            coords_changed = true;
+        }
+        if coords_changed {
+            color = color_from_coords(self.mode, state.coords, color.a);
+            reformat = true;
+        }
+        if self.alpha && let Some(pos) = position(ui, COLOR_PICKER_ALPHA) {
+            color.a = pos.x;
+            reformat = true;
+        }
+        for key in [COLOR_PICKER_SQUARE, COLOR_PICKER_HUE, COLOR_PICKER_ALPHA] {
+            if ui.is_drag_released(key) {
+                commit = true;
+            }
+        }
+
+        // Swatches
+        let swatches = self.palette.iter().map(|c| (COLOR_PICKER_SWATCH, *c)).enumerate()
+            .chain(state.recent.iter().map(|c| (COLOR_PICKER_RECENT, *c)).enumerate());
+        for (i, (key, swatch)) in swatches {
+            if ui.is_clicked(key.sibling(i)) {
+                color = swatch;
+                if ! self.alpha {
+                    color.a = initial_color.a;
+                }
+                state.coords = coords_from_color(self.mode, color, state.coords);
+                reformat = true;
+            }
+        }
+
+        // Typing
+        let mut typed_field = None;
+        for field in 0..4 {
+            let key = COLOR_PICKER_FIELD.sibling(field);
+            let editing = ui.sys.focused == Some(key.id_with_key_scope());
+
+            let typed = ui.get_node(key).and_then(|n| n.text_edit_changed().map(str::to_string));
+            if let Some(typed) = typed {
+                state.texts[field] = typed;
+                match self.parse(&state.texts[field], field, color) {
+                    Some(parsed) => {
+                        color = parsed;
+                        state.coords = coords_from_color(self.mode, color, state.coords);
+                        state.invalid[field] = false;
+                        typed_field = Some(field);
+                    }
+                    None => state.invalid[field] = true,
+                }
+            }
+
+            let committed = (editing && ui.key_input().key_pressed(&Key::Named(NamedKey::Enter)))
+                || (state.was_editing[field] && ! editing);
+            if committed && ! state.invalid[field] {
+                commit = true;
+                reformat = true;
+            }
+            state.was_editing[field] = editing;
+        }
+
+        if reformat || typed_field.is_some() {
+            for field in 0..4 {
+                if ! reformat && typed_field == Some(field) {
+                    continue;
+                }
+                state.texts[field] = self.format(color, field);
+                state.invalid[field] = false;
+                // The declarative text is only applied when it changes, but the typed text might differ from it even if the formatted color didn't.
+                if let Some(edit) = ui.get_node_mut(COLOR_PICKER_FIELD.sibling(field)) {
+                    edit.set_text(&state.texts[field]);
+                }
+            }
+        }
+
+        if commit {
+            state.recent.retain(|c| to_array(*c) != to_array(color));
+            state.recent.insert(0, color);
+            state.recent.truncate(COLOR_PICKER_MAX_RECENT);
+        }
+
+        *self.color = color;
+        state.last_color = Some(to_array(color));
+
+        self.add_nodes(ui, state, color, [COLOR_PICKER_SQUARE, COLOR_PICKER_HUE, COLOR_PICKER_ALPHA, COLOR_PICKER_FIELD, COLOR_PICKER_SWATCH, COLOR_PICKER_RECENT]);
+
+        return to_array(color) != to_array(initial_color);
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
+    }
+}
+
+impl ColorPicker<'_> {
+    fn add_nodes(&self, ui: &mut Ui, state: &ColorPickerState, color: Color, keys: [NodeKey; 6]) {
+        let [square_key, hue_key, alpha_key, field_key, swatch_key, recent_key] = keys;
+        let [hue, x, y] = state.coords;
+        let opaque = color.with_alpha(1.0);
+        let white = Color::WHITE;
+        let black = Color::BLACK;
+
+        let square = PANEL
+            .size_symm(Size::Pixels(COLOR_PICKER_SIZE))
+            .padding(0.0)
+            .sense_click(true)
+            .sense_drag(true)
+            .key(square_key);
+
+        // A thin decoration that doesn't get in the way of the clicks on its parent.
+        let overlay = PANEL
+            .size_symm(Size::Fill)
+            .padding(0.0)
+            .absorbs_clicks(false);
+
+        let cursor = CONTAINER
+            .size_symm(Size::Pixels(12.0))
+            .shape(Shape::Ring { width: 2.0 })
+            .color(white)
+            .visible()
+            .free_placement(true)
+            .position(Pos::Frac(x), Pos::Frac(1.0 - y))
+            .anchor_symm(Anchor::Center)
+            .absorbs_clicks(false);
+
+        let hue_bar = V_STACK
+            .size_x(Size::Pixels(COLOR_PICKER_BAR_WIDTH))
+            .size_y(Size::Pixels(COLOR_PICKER_SIZE))
+            .stack_spacing(0.0)
+            .padding(0.0)
+            .sense_click(true)
+            .sense_drag(true)
+            .accessibility_role(AccessKitRole::Slider)
+            .accessibility_numeric_value((hue * 360.0) as f64, 0.0, 360.0)
+            .key(hue_key);
+
+        let hue_segments = match self.mode {
+            ColorPickerMode::Hsv => 6,
+            ColorPickerMode::OkLch => 12,
+        };
+
+        let bar_cursor = PANEL
+            .padding(0.0)
+            .shape(Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 1.0 })
+            .color(white)
+            .free_placement(true)
+            .absorbs_clicks(false);
+
+        ui.add(V_STACK.stack_spacing(8.0).accessibility_role(AccessKitRole::ColorWell)).nest(|| {
+            ui.add(H_STACK.stack_spacing(8.0)).nest(|| {
+                ui.add(square).nest(|| {
+                    match self.mode {
+                        ColorPickerMode::Hsv => {
+                            let full = hsv_to_rgb([hue, 1.0, 1.0], 1.0);
+                            ui.add(overlay.color(full));
+                            ui.add(overlay.linear_gradient(LinearGradient::new(white, white.with_alpha(0.0), 0.0)));
+                            ui.add(overlay.linear_gradient(LinearGradient::new(black.with_alpha(0.0), black, 90.0)));
+                        }
+                        ColorPickerMode::OkLch => {
+                            ui.add(V_STACK.size_symm(Size::Fill).stack_spacing(0.0).padding(0.0).absorbs_clicks(false)).nest(|| {
+                                for row in 0..OKLCH_ROWS {
+                                    let y = 1.0 - (row as f32 + 0.5) / OKLCH_ROWS as f32;
+                                    let row_node = H_STACK
+                                        .size_x(Size::Fill)
+                                        .size_y(Size::Frac(1.0 / OKLCH_ROWS as f32))
+                                        .stack_spacing(0.0)
+                                        .padding(0.0)
+                                        .absorbs_clicks(false);
+                                    ui.add(row_node.key(square_key.sibling(row))).nest(|| {
+                                        for column in 0..OKLCH_COLUMNS {
+                                            let x0 = column as f32 / OKLCH_COLUMNS as f32;
+                                            let x1 = (column + 1) as f32 / OKLCH_COLUMNS as f32;
+                                            let start = color_from_coords(ColorPickerMode::OkLch, [hue, x0, y], 1.0);
+                                            let end = color_from_coords(ColorPickerMode::OkLch, [hue, x1, y], 1.0);
+                                            let cell = overlay
+                                                .size_x(Size::Frac(1.0 / OKLCH_COLUMNS as f32))
+                                                .size_y(Size::Fill)
+                                                .shape(Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 0.0 })
+                                                .linear_gradient(LinearGradient::new(start, end, 0.0));
+                                            ui.add(cell.key(square_key.sibling((row, column))));
+                                        }
+                                    });
+                                }
+                            });
+                        }
+                    }
+                    ui.add(cursor);
+                });
+
+                ui.add(hue_bar).nest(|| {
+                    for i in 0..hue_segments {
+                        let t0 = i as f32 / hue_segments as f32;
+                        let t1 = (i + 1) as f32 / hue_segments as f32;
+                        let segment = overlay
+                            .size_y(Size::Frac(1.0 / hue_segments as f32))
+                            .shape(Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 0.0 })
+                            .linear_gradient(LinearGradient::new(hue_color(self.mode, t0), hue_color(self.mode, t1), 90.0));
+                        ui.add(segment.key(hue_key.sibling(i)));
+                    }
+                    let bar_cursor = bar_cursor
+                        .size_x(Size::Fill)
+                        .size_y(Size::Pixels(3.0))
+                        .position_y(Pos::Frac(hue))
+                        .anchor_y(Anchor::Center);
+                    ui.add(bar_cursor);
+                });
+            });
+
+            if self.alpha {
+                let alpha_bar = PANEL
+                    .size_x(Size::Pixels(COLOR_PICKER_SIZE + 8.0 + COLOR_PICKER_BAR_WIDTH))
+                    .size_y(Size::Pixels(COLOR_PICKER_BAR_WIDTH))
+                    .padding(0.0)
+                    .color(Color::GREY)
+                    .sense_click(true)
+                    .sense_drag(true)
+                    .accessibility_role(AccessKitRole::Slider)
+                    .accessibility_numeric_value(color.a as f64, 0.0, 1.0)
+                    .key(alpha_key);
+
+                ui.add(alpha_bar).nest(|| {
+                    ui.add(overlay.linear_gradient(LinearGradient::new(opaque.with_alpha(0.0), opaque, 0.0)));
+                    let bar_cursor = bar_cursor
+                        .size_x(Size::Pixels(3.0))
+                        .size_y(Size::Fill)
+                        .position_x(Pos::Frac(color.a))
+                        .anchor_x(Anchor::Center);
+                    ui.add(bar_cursor);
+                });
+            }
+
+            ui.add(H_STACK.stack_spacing(5.0)).nest(|| {
+                let preview = PANEL
+                    .size_x(Size::Pixels(40.0))
+                    .size_y(Size::Fill)
+                    .padding(0.0)
+                    .color(color);
+                ui.add(preview);
+
+                for field in 0..4 {
+                    if field == 1 {
+                        ui.static_label("RGB");
+                    }
+                    let width = if field == 0 { 100.0 } else { 45.0 };
+                    let mut edit = TEXT_EDIT_LINE
+                        .text(&state.texts[field])
+                        .size_x(Size::Pixels(width))
+                        .key(field_key.sibling(field));
+                    if state.invalid[field] {
+                        edit = edit.stroke(2.0).stroke_fill(ui.theme().error);
+                    }
+                    ui.add(edit);
+                }
+            });
+
+            let swatch = PANEL
+                .size_symm(Size::Pixels(20.0))
+                .padding(0.0)
+                .sense_click(true)
+                .stroke(1.0)
+                .stroke_fill(ui.theme().border);
+
+            if ! self.palette.is_empty() {
+                ui.add(H_STACK.stack_spacing(4.0)).nest(|| {
+                    for (i, c) in self.palette.iter().enumerate() {
+                        ui.add(swatch.color(*c).key(swatch_key.sibling(i)));
+                    }
+                });
+            }
+            if ! state.recent.is_empty() {
+                ui.add(H_STACK.stack_spacing(4.0)).nest(|| {
+                    for (i, c) in state.recent.iter().enumerate() {
+                        ui.add(swatch.color(*c).key(recent_key.sibling(i)));
+                    }
+                });
+            }
+        });
+    }
+}
+
+fn to_array(c: Color) -> [f32; 4] {
+    [c.r, c.g, c.b, c.a]
+}
+
+fn to_u8(c: Color) -> [u8; 4] {
+    to_array(c).map(|x| (x.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+fn from_u8(c: [u8; 4]) -> Color {
+    let [r, g, b, a] = c.map(|x| x as f32 / 255.0);
+    Color::new(r, g, b, a)
+}
+
+// The color at the top of the hue bar for a given hue.
+fn hue_color(mode: ColorPickerMode, hue: f32) -> Color {
+    match mode {
+        ColorPickerMode::Hsv => hsv_to_rgb([hue, 1.0, 1.0], 1.0),
+        ColorPickerMode::OkLch => oklch_to_srgb([0.75, 0.13, hue * 360.0], 1.0),
+    }
+}
+
+fn color_from_coords(mode: ColorPickerMode, coords: [f32; 3], alpha: f32) -> Color {
+    let [hue, x, y] = coords;
+    match mode {
+        ColorPickerMode::Hsv => hsv_to_rgb([hue, x, y], alpha),
+        ColorPickerMode::OkLch => oklch_to_srgb([y, x * OKLCH_MAX_CHROMA, hue * 360.0], alpha),
+    }
+}
+
+// Keeps the previous hue when the color has none, and the previous saturation when the color is black.
+fn coords_from_color(mode: ColorPickerMode, color: Color, previous: [f32; 3]) -> [f32; 3] {
+    match mode {
+        ColorPickerMode::Hsv => {
+            let [h, s, v] = rgb_to_hsv(color);
+            let s = if v <= f32::EPSILON { previous[1] } else { s };
+            let h = if s <= f32::EPSILON { previous[0] } else { h };
+            [h, s, v]
+        }
+        ColorPickerMode::OkLch => {
+            let [l, c, h] = srgb_to_oklch(color);
+            let h = if c <= 1e-4 { previous[0] } else { h / 360.0 };
+            [h, (c / OKLCH_MAX_CHROMA).min(1.0), l.clamp(0.0, 1.0)]
+        }
+    }
+}
+
+fn hsv_to_rgb(hsv: [f32; 3], alpha: f32) -> Color {
+    let [h, s, v] = hsv;
+    let h = (h.rem_euclid(1.0)) * 6.0;
+    let f = |n: f32| {
+        let k = (n + h) % 6.0;
+        v - v * s * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+    Color::new(f(5.0), f(3.0), f(1.0), alpha)
+}
+
+fn rgb_to_hsv(c: Color) -> [f32; 3] {
+    let max = c.r.max(c.g).max(c.b);
+    let min = c.r.min(c.g).min(c.b);
+    let delta = max - min;
+    let h = if delta <= 0.0 {
+        0.0
+    } else if max == c.r {
+        ((c.g - c.b) / delta).rem_euclid(6.0)
+    } else if max == c.g {
+        (c.b - c.r) / delta + 2.0
+    } else {
+        (c.r - c.g) / delta + 4.0
+    };
+    let s = if max <= 0.0 { 0.0 } else { delta / max };
+    [h / 6.0, s, max]
+}
+
+fn srgb_to_linear(x: f32) -> f32 {
+    if x <= 0.04045 { x / 12.92 } else { ((x + 0.055) / 1.055).powf(2.4) }
+}
+
+fn linear_to_srgb(x: f32) -> f32 {
+    if x <= 0.0031308 { x * 12.92 } else { 1.055 * x.powf(1.0 / 2.4) - 0.055 }
+}
+
+// Lightness, chroma and hue in degrees.
+fn srgb_to_oklch(c: Color) -> [f32; 3] {
+    let [r, g, b] = [c.r, c.g, c.b].map(srgb_to_linear);
+
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    let lightness = 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s;
+    let a = 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s;
+    let b = 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s;
+
+    let hue = b.atan2(a).to_degrees().rem_euclid(360.0);
+    [lightness, (a * a + b * b).sqrt(), hue]
+}
+
+fn oklch_to_srgb(lch: [f32; 3], alpha: f32) -> Color {
+    let [lightness, chroma, hue] = lch;
+    let a = chroma * hue.to_radians().cos();
+    let b = chroma * hue.to_radians().sin();
+
+    let l = lightness + 0.3963377774 * a + 0.2158037573 * b;
+    let m = lightness - 0.1055613458 * a - 0.0638541728 * b;
+    let s = lightness - 0.0894841775 * a - 1.2914855480 * b;
+    let [l, m, s] = [l * l * l, m * m * m, s * s * s];
+
+    let rgb = [
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    ];
+    let [r, g, b] = rgb.map(|x| linear_to_srgb(x.clamp(0.0, 1.0)));
+    Color::new(r, g, b, alpha)
+}
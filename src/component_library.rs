@@ -195,6 +195,7 @@ impl Ui {
                     .size_y(Size::Pixels(45.0))
                     .sense_drag(true)
                     .focusable(true)
+                    .uses_arrow_keys(true)
                     .accessibility_role(AccessKitRole::Slider)
                     .accessibility_numeric_value(*value as f64, min as f64, max as f64)
                     .accessibility_actions(AccessibilityActions::INCREMENT | AccessibilityActions::DECREMENT)
@@ -308,6 +309,7 @@ impl Ui {
                 .sense_click(true)
                 .sense_drag(true)
                 .focusable(true)
+                .uses_arrow_keys(true)
                 .accessibility_role(AccessKitRole::Slider)
                 .accessibility_numeric_value(*value as f64, min as f64, max as f64)
                 .accessibility_actions(AccessibilityActions::INCREMENT | AccessibilityActions::DECREMENT)
@@ -328,51 +330,177 @@ impl Ui {
 
 // Trait components
 
+/// How a [`Slider`] maps its position to its value.
+#[derive(Clone, Copy, Debug)]
+pub enum SliderScale {
+    /// The value changes at the same rate along the whole slider.
+    Linear,
+    /// Every ratio takes the same space, so 10 to 100 is as long as 100 to 1000. Good for frequencies and zoom levels.
+    ///
+    /// `min` and `max` must both be positive.
+    Logarithmic,
+    /// For gains in decibels. The position follows the cube root of the amplitude, like a mixer fader: the range near the top gets more space than the quiet end.
+    ///
+    /// `min` can be `f32::NEG_INFINITY`, for a fader that goes to silence.
+    Decibels,
+    /// A custom mapping. `to_position` maps a value to a position between 0.0 and 1.0, and `from_position` maps it back.
+    Custom {
+        to_position: fn(f32) -> f32,
+        from_position: fn(f32) -> f32,
+    },
+}
+
+impl SliderScale {
+    fn to_position(self, value: f32, min: f32, max: f32) -> f32 {
+        match self {
+            SliderScale::Linear => (value - min) / (max - min),
+            SliderScale::Logarithmic => (value / min).ln() / (max / min).ln(),
+            SliderScale::Decibels => {
+                let (a, a_min, a_max) = (db_to_fader(value), db_to_fader(min), db_to_fader(max));
+                (a - a_min) / (a_max - a_min)
+            }
+            SliderScale::Custom { to_position, .. } => to_position(value),
+        }
+    }
+
+    fn from_position(self, position: f32, min: f32, max: f32) -> f32 {
+        match self {
+            SliderScale::Linear => min + position * (max - min),
+            SliderScale::Logarithmic => min * (max / min).powf(position),
+            SliderScale::Decibels => {
+                let (a_min, a_max) = (db_to_fader(min), db_to_fader(max));
+                60.0 * (a_min + position * (a_max - a_min)).log10()
+            }
+            SliderScale::Custom { from_position, .. } => from_position(position),
+        }
+    }
+}
+
+// Cube root of the amplitude.
+fn db_to_fader(db: f32) -> f32 {
+    10.0_f32.powf(db / 60.0)
+}
+
+/// The internal state of a [`Slider`].
+#[derive(Default, Debug)]
+pub struct SliderState {
+    // Unsnapped position during a drag, so that slow drags still move between steps.
+    drag_position: Option<f32>,
+    last_click: Option<std::time::Instant>,
+}
+
+/// A slider for a `f32` value.
+///
+/// Dragging it moves the value relative to where it was. Holding Shift while dragging or pressing the arrow keys makes finer adjustments, and double-clicking resets the value to [`Slider::default_value`], if set.
+///
+/// ```no_run
+/// # use keru::*; let mut ui: Ui = unimplemented!();
+/// let mut frequency = 440.0;
+/// let format_hz = |hz: f32| format!("{hz:.0} Hz");
+/// ui.add_component(
+///     Slider::new(&mut frequency, 20.0, 20000.0, true)
+///         .scale(SliderScale::Logarithmic)
+///         .default_value(440.0)
+///         .format(&format_hz)
+/// );
+/// ```
 pub struct Slider<'a> {
     pub value: &'a mut f32,
     pub min: f32,
     pub max: f32,
     pub clamp: bool, // todo: with clamp = false, still clamp values set WITH the slider
+    pub scale: SliderScale,
+    pub step: Option<f32>,
+    pub default_value: Option<f32>,
+    pub format: Option<&'a dyn Fn(f32) -> String>,
 }
 
-impl SimpleComponent for Slider<'_> {
-    fn add_to_ui(&mut self, ui: &mut Ui) {
+const SLIDER_FINE_FACTOR: f32 = 0.1;
+const SLIDER_DOUBLE_CLICK_TIME: std::time::Duration = std::time::Duration::from_millis(400);
+
+impl Component for Slider<'_> {
+    type AddResult = ();
+    type ComponentOutput = ();
+    type State = SliderState;
+
+    fn add_to_ui(&mut self, ui: &mut Ui, state: &mut Self::State) {
         with_arena(|a| {
 
             #[node_key] const SLIDER_FILL: NodeKey;
             #[node_key] const SLIDER_LABEL: NodeKey;
             #[node_key] const SLIDER_CONTAINER: NodeKey;
-                
+
+            let (min, max, scale) = (self.min, self.max, self.scale);
+            let fine = ui.key_input().key_mods().shift_key();
+            let speed = if fine { SLIDER_FINE_FACTOR } else { 1.0 };
+
             let mut new_value = *self.value;
             if let Some(drag) = ui.is_dragged(SLIDER_CONTAINER) {
-                new_value += drag.relative_delta.x as f32 * (self.max - self.min);
+                let start = state.drag_position.unwrap_or_else(|| scale.to_position(new_value, min, max));
+                let mut position = start + drag.relative_delta.x * speed;
+                if self.clamp {
+                    position = position.clamp(0.0, 1.0);
+                }
+                state.drag_position = Some(position);
+                new_value = self.snap(scale.from_position(position, min, max));
+            } else {
+                state.drag_position = None;
             }
 
             // Arrow keys do a "drag" when the slider is focused.
+            let mut steps = 0.0;
             if ui.is_focused(SLIDER_CONTAINER) {
-                let step = (self.max - self.min) * 0.01;
                 if ui.key_input().key_pressed_or_repeated(&winit::keyboard::Key::Named(winit::keyboard::NamedKey::ArrowLeft)) {
-                    new_value -= step;
+                    steps -= 1.0;
                 }
                 if ui.key_input().key_pressed_or_repeated(&winit::keyboard::Key::Named(winit::keyboard::NamedKey::ArrowRight)) {
-                    new_value += step;
+                    steps += 1.0;
                 }
             }
+            if ui.accesskit_action(SLIDER_CONTAINER, AccessKitAction::Increment) {
+                steps += 1.0;
+            }
+            if ui.accesskit_action(SLIDER_CONTAINER, AccessKitAction::Decrement) {
+                steps -= 1.0;
+            }
+            if steps != 0.0 {
+                new_value = match self.step {
+                    Some(step) => self.snap(new_value + steps * step),
+                    None => {
+                        let position = scale.to_position(new_value, min, max) + steps * 0.01 * speed;
+                        scale.from_position(position, min, max)
+                    }
+                };
+            }
 
-            if new_value.is_finite() {
+            if ui.is_clicked(SLIDER_CONTAINER) {
+                let now = std::time::Instant::now();
+                let double_click = state.last_click.is_some_and(|last| now.duration_since(last) < SLIDER_DOUBLE_CLICK_TIME);
+                if double_click && let Some(default_value) = self.default_value {
+                    new_value = default_value;
+                    state.last_click = None;
+                } else {
+                    state.last_click = Some(now);
+                }
+            }
+
+            if new_value.is_finite() || (scale_allows_infinity(scale) && new_value == f32::NEG_INFINITY) {
                 if self.clamp {
-                    new_value = new_value.clamp(self.min, self.max);
+                    new_value = new_value.clamp(min, max);
                 }
                 *self.value = new_value;
             }
 
-            let filled_frac = (*self.value - self.min) / (self.max - self.min);
+            let filled_frac = scale.to_position(*self.value, min, max).clamp(0.0, 1.0);
+            let filled_frac = if filled_frac.is_nan() { 0.0 } else { filled_frac };
 
             let slider_container = PANEL
                 .size_x(Size::Fill)
                 .size_y(Size::Pixels(45.0))
                 .sense_drag(true)
+                .sense_click(true)
                 .focusable(true)
+                .uses_arrow_keys(true)
                 .accessibility_role(AccessKitRole::Slider)
                 .accessibility_numeric_value(*self.value as f64, self.min as f64, self.max as f64)
                 .accessibility_actions(AccessibilityActions::INCREMENT | AccessibilityActions::DECREMENT)
@@ -389,8 +517,10 @@ impl SimpleComponent for Slider<'_> {
                 .shape(Shape::Rectangle { corner_radius: 9.0, rounded_corners: RoundedCorners::ALL })
                 .key(SLIDER_FILL);
 
-
-            let text = bumpalo::format!(in a, "{:.2}", self.value);
+            let text = match self.format {
+                Some(format) => bumpalo::collections::String::from_str_in(&format(*self.value), a),
+                None => bumpalo::format!(in a, "{:.2}", self.value),
+            };
             let label = TEXT.text(&text).key(SLIDER_LABEL);
 
             ui.add(slider_container).nest(|| {
@@ -402,9 +532,474 @@ impl SimpleComponent for Slider<'_> {
     }
 }
 
+// A decibel fader can go all the way down to silence.
+fn scale_allows_infinity(scale: SliderScale) -> bool {
+    matches!(scale, SliderScale::Decibels)
+}
+
 impl<'a> Slider<'a> {
     pub fn new(value: &'a mut f32, min: f32, max: f32, clamp: bool) -> Self {
-        Self { value, min, max, clamp }
+        Self {
+            value,
+            min,
+            max,
+            clamp,
+            scale: SliderScale::Linear,
+            step: None,
+            default_value: None,
+            format: None,
+        }
+    }
+
+    pub fn scale(mut self, scale: SliderScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Snap the value to multiples of `step`, counting from `min`.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// The value that double-clicking the slider resets to.
+    pub fn default_value(mut self, default_value: f32) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+
+    /// Customize the text shown on the slider.
+    pub fn format(mut self, format: &'a dyn Fn(f32) -> String) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    fn snap(&self, value: f32) -> f32 {
+        let Some(step) = self.step.filter(|s| *s > 0.0) else {
+            return value;
+        };
+        let origin = if self.min.is_finite() { self.min } else { 0.0 };
+        return origin + ((value - origin) / step).round() * step;
+    }
+}
+
+/// A slider with two handles, for selecting a range of values.
+///
+/// The handles can't cross each other. When a handle has focus, the arrow keys move it by one step, and Home and End move it as far as it can go.
+///
+/// ```no_run
+/// # use keru::*; let mut ui: Ui = unimplemented!();
+/// let mut price = (50.0, 300.0);
+/// ui.add_component(RangeSlider::new(&mut price, 0.0, 500.0).step(10.0).ticks(100.0));
+/// ```
+pub struct RangeSlider<'a> {
+    pub value: &'a mut (f32, f32),
+    pub min: f32,
+    pub max: f32,
+    pub step: Option<f32>,
+    pub tick_interval: Option<f32>,
+    pub key: Option<ComponentKey<Self>>,
+}
+
+impl<'a> RangeSlider<'a> {
+    pub fn new(value: &'a mut (f32, f32), min: f32, max: f32) -> Self {
+        Self { value, min, max, step: None, tick_interval: None, key: None }
+    }
+
+    /// Snap the values to multiples of `step`, counting from `min`.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Show a tick mark every `interval`, counting from `min`.
+    pub fn ticks(mut self, interval: f32) -> Self {
+        self.tick_interval = Some(interval);
+        self
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    fn snap(&self, value: f32) -> f32 {
+        let value = match self.step {
+            Some(step) if step > 0.0 => self.min + ((value - self.min) / step).round() * step,
+            _ => value,
+        };
+        return value.clamp(self.min, self.max);
+    }
+
+    fn frac(&self, value: f32) -> f32 {
+        return ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0);
+    }
+}
+
+const RANGE_SLIDER_MAX_TICKS: usize = 200;
+
+/// The internal state of a [`RangeSlider`].
+#[derive(Default, Debug)]
+pub struct RangeSliderState {
+    // Whether the current drag moves the low handle. Decided by the first movement when the handles overlap.
+    dragging_low: Option<bool>,
+}
+
+impl Component for RangeSlider<'_> {
+    /// `true` if either value was changed this frame.
+    type AddResult = bool;
+    type ComponentOutput = ();
+    type State = RangeSliderState;
+
+    fn add_to_ui(&mut self, ui: &mut Ui, state: &mut Self::State) -> Self::AddResult {
+        use winit::keyboard::{Key, NamedKey};
+
+        #[node_key] const RANGE_SLIDER_CONTAINER: NodeKey;
+        #[node_key] const RANGE_SLIDER_TRACK: NodeKey;
+        #[node_key] const RANGE_SLIDER_FILL: NodeKey;
+        #[node_key] const RANGE_SLIDER_LOW: NodeKey;
+        #[node_key] const RANGE_SLIDER_HIGH: NodeKey;
+        #[node_key] const RANGE_SLIDER_TICK: NodeKey;
+
+        let initial_value = *self.value;
+        let (mut low, mut high) = initial_value;
+        if low > high {
+            std::mem::swap(&mut low, &mut high);
+        }
+
+        let keyboard_step = self.step.unwrap_or((self.max - self.min) * 0.01);
+
+        // Converts a cursor position in logical pixels into a value, using the track's rect from last frame.
+        // This is the same conversion as the `relative_position` of clicks and drags.
+        let track_rect = ui.sys.nodes.get_with_key_scope(RANGE_SLIDER_TRACK).map(|i| ui.sys.nodes[i].real_rect);
+        let logical_width = ui.sys.logical_size().x;
+        let value_at = |x: f32| -> Option<f32> {
+            let rect = track_rect?;
+            let width = rect.size().x;
+            if width <= 0.0 {
+                return None;
+            }
+            let frac = ((x / logical_width - rect.x[0]) / width).clamp(0.0, 1.0);
+            return Some(self.min + frac * (self.max - self.min));
+        };
+
+        // Clicking the track moves the closest handle.
+        if let Some(click) = ui.clicked_at(RANGE_SLIDER_CONTAINER) && let Some(v) = value_at(click.absolute_position.x) {
+            let v = self.snap(v);
+            if (v - low).abs() <= (v - high).abs() {
+                low = v.min(high);
+            } else {
+                high = v.max(low);
+            }
+        }
+
+        // When the handles overlap, the one on top gets every drag, so the handle that moves is picked by the direction of the drag instead.
+        let drag = [(RANGE_SLIDER_LOW, true), (RANGE_SLIDER_HIGH, false)].into_iter()
+            .find_map(|(key, is_low)| Some((ui.is_dragged(key)?, is_low)));
+        match drag {
+            Some((drag, dragged_low)) => {
+                if let Some(v) = value_at(drag.absolute_pos.x) {
+                    let v = self.snap(v);
+                    if state.dragging_low.is_none() && (low != high || v != low) {
+                        state.dragging_low = Some(if low == high { v < low } else { dragged_low });
+                    }
+                    match state.dragging_low {
+                        Some(true) => low = v.max(self.min).min(high),
+                        Some(false) => high = v.min(self.max).max(low),
+                        None => {}
+                    }
+                }
+            }
+            None => state.dragging_low = None,
+        }
+
+        for (key, is_low) in [(RANGE_SLIDER_LOW, true), (RANGE_SLIDER_HIGH, false)] {
+            let (current, lower_bound, upper_bound) = if is_low {
+                (low, self.min, high)
+            } else {
+                (high, low, self.max)
+            };
+            let mut new_value = current;
+
+            let focused = ui.sys.focused == Some(key.id_with_key_scope());
+            if focused {
+                let pressed = |k: NamedKey| ui.key_input().key_pressed_or_repeated(&Key::Named(k));
+                if pressed(NamedKey::ArrowRight) || pressed(NamedKey::ArrowUp) {
+                    new_value = self.snap(new_value + keyboard_step);
+                }
+                if pressed(NamedKey::ArrowLeft) || pressed(NamedKey::ArrowDown) {
+                    new_value = self.snap(new_value - keyboard_step);
+                }
+                if pressed(NamedKey::Home) {
+                    new_value = lower_bound;
+                }
+                if pressed(NamedKey::End) {
+                    new_value = upper_bound;
+                }
+            }
+            if ui.accesskit_action(key, AccessKitAction::Increment) {
+                new_value = self.snap(new_value + keyboard_step);
+            }
+            if ui.accesskit_action(key, AccessKitAction::Decrement) {
+                new_value = self.snap(new_value - keyboard_step);
+            }
+
+            let new_value = new_value.clamp(lower_bound, upper_bound);
+            if new_value.is_finite() {
+                if is_low {
+                    low = new_value;
+                } else {
+                    high = new_value;
+                }
+            }
+        }
+
+        *self.value = (low, high);
+
+        let (low_frac, high_frac) = (self.frac(low), self.frac(high));
+
+        let container = CONTAINER
+            .size(Size::Fill, Size::Pixels(36.0))
+            .padding(0.0)
+            .sense_click(true)
+            .key(RANGE_SLIDER_CONTAINER);
+
+        let track = PANEL
+            .size(Size::Fill, Size::Pixels(6.0))
+            .padding(0.0)
+            .position_y(Pos::Center)
+            .shape(Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 3.0 })
+            .fill(ui.theme().border)
+            .absorbs_clicks(false)
+            .key(RANGE_SLIDER_TRACK);
+
+        let fill = PANEL
+            .size(Size::Frac(high_frac - low_frac), Size::Fill)
+            .padding(0.0)
+            .position_x(Pos::Frac(low_frac))
+            .shape(Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 3.0 })
+            .fill(ui.theme().primary)
+            .absorbs_clicks(false)
+            .key(RANGE_SLIDER_FILL);
+
+        let handle = PANEL
+            .size_symm(Size::Pixels(20.0))
+            .padding(0.0)
+            .anchor_symm(Anchor::Center)
+            .shape(Shape::Circle)
+            .fill(ui.theme().text_primary)
+            .sense_drag(true)
+            .focusable(true)
+            .uses_arrow_keys(true)
+            .accessibility_role(AccessKitRole::Slider)
+            .accessibility_actions(AccessibilityActions::INCREMENT | AccessibilityActions::DECREMENT);
+
+        // Each handle reports the range that it can actually move in.
+        let low_handle = handle
+            .position(Pos::Frac(low_frac), Pos::Center)
+            .accessibility_numeric_value(low as f64, self.min as f64, high as f64)
+            .key(RANGE_SLIDER_LOW);
+        let high_handle = handle
+            .position(Pos::Frac(high_frac), Pos::Center)
+            .accessibility_numeric_value(high as f64, low as f64, self.max as f64)
+            .key(RANGE_SLIDER_HIGH);
+
+        let tick = PANEL
+            .size(Size::Pixels(1.0), Size::Pixels(6.0))
+            .padding(0.0)
+            .anchor_x(Anchor::Center)
+            .shape(Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 0.0 })
+            .fill(ui.theme().text_disabled)
+            .absorbs_clicks(false);
+
+        ui.add(container).nest(|| {
+            if let Some(interval) = self.tick_interval && interval > 0.0 {
+                let n_ticks = (((self.max - self.min) / interval).floor() as usize + 1).min(RANGE_SLIDER_MAX_TICKS);
+                for n in 0..n_ticks {
+                    let frac = self.frac(self.min + n as f32 * interval);
+                    ui.add(tick.position(Pos::Frac(frac), Pos::End).key(RANGE_SLIDER_TICK.sibling(n)));
+                }
+            }
+
+            ui.add(track).nest(|| {
+                ui.add(fill);
+                ui.add(low_handle);
+                ui.add(high_handle);
+            });
+        });
+
+        return *self.value != initial_value;
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
+    }
+}
+
+/// A rotary knob for a `f32` value, like the ones on audio equipment.
+///
+/// Dragging up or down and scrolling change the value. Holding Shift makes finer adjustments.
+///
+/// ```no_run
+/// # use keru::*; let mut ui: Ui = unimplemented!();
+/// let mut pan = 0.0;
+/// ui.add_component(Knob::new(&mut pan, -1.0, 1.0).bipolar(true));
+/// ```
+pub struct Knob<'a> {
+    pub value: &'a mut f32,
+    pub min: f32,
+    pub max: f32,
+    /// Diameter in pixels.
+    pub size: f32,
+    /// How many pixels of dragging it takes to go from `min` to `max`.
+    pub sensitivity: f32,
+    /// Draw the value arc starting from zero instead of from `min`, for values like pan or detune.
+    pub bipolar: bool,
+    pub key: Option<ComponentKey<Self>>,
+}
+
+impl<'a> Knob<'a> {
+    pub fn new(value: &'a mut f32, min: f32, max: f32) -> Self {
+        Self {
+            value,
+            min,
+            max,
+            size: 46.0,
+            sensitivity: 200.0,
+            bipolar: false,
+            key: None,
+        }
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    pub fn bipolar(mut self, bipolar: bool) -> Self {
+        self.bipolar = bipolar;
+        self
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    fn frac(&self, value: f32) -> f32 {
+        return ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0);
+    }
+}
+
+// The arc goes from bottom-left to bottom-right, leaving a gap at the bottom.
+const KNOB_START_ANGLE: f32 = std::f32::consts::PI * 0.75;
+const KNOB_END_ANGLE: f32 = std::f32::consts::PI * 2.25;
+// Fraction of the range per unit of scroll. A scroll wheel notch is 0.4 units.
+const KNOB_SCROLL_SPEED: f32 = 0.05;
+
+impl Component for Knob<'_> {
+    /// `true` if the value was changed this frame.
+    type AddResult = bool;
+    type ComponentOutput = ();
+    type State = ();
+
+    fn add_to_ui(&mut self, ui: &mut Ui, _state: &mut Self::State) -> Self::AddResult {
+        use winit::keyboard::{Key, NamedKey};
+
+        #[node_key] const KNOB: NodeKey;
+
+        let initial_value = *self.value;
+        let range = self.max - self.min;
+        let speed = if ui.key_input().key_mods().shift_key() { 0.1 } else { 1.0 };
+
+        let mut value = initial_value;
+        if let Some(drag) = ui.is_dragged(KNOB) {
+            value -= drag.absolute_delta.y / self.sensitivity * range * speed;
+        }
+        if let Some(scroll) = ui.is_scrolled(KNOB) {
+            value += scroll.y * KNOB_SCROLL_SPEED * range * speed;
+        }
+
+        let mut steps = 0.0;
+        if ui.sys.focused == Some(KNOB.id_with_key_scope()) {
+            let pressed = |k: NamedKey| ui.key_input().key_pressed_or_repeated(&Key::Named(k));
+            if pressed(NamedKey::ArrowUp) || pressed(NamedKey::ArrowRight) {
+                steps += 1.0;
+            }
+            if pressed(NamedKey::ArrowDown) || pressed(NamedKey::ArrowLeft) {
+                steps -= 1.0;
+            }
+        }
+        if ui.accesskit_action(KNOB, AccessKitAction::Increment) {
+            steps += 1.0;
+        }
+        if ui.accesskit_action(KNOB, AccessKitAction::Decrement) {
+            steps -= 1.0;
+        }
+        value += steps * range * 0.01 * speed;
+
+        if value.is_finite() {
+            *self.value = value.clamp(self.min, self.max);
+        }
+
+        let angle_at = |frac: f32| KNOB_START_ANGLE + (KNOB_END_ANGLE - KNOB_START_ANGLE) * frac;
+        let value_angle = angle_at(self.frac(*self.value));
+        let origin_angle = if self.bipolar { angle_at(self.frac(0.0)) } else { KNOB_START_ANGLE };
+        let (arc_start, arc_end) = if value_angle < origin_angle {
+            (value_angle, origin_angle)
+        } else {
+            (origin_angle, value_angle)
+        };
+        let arc_width = self.size * 0.12;
+
+        let knob = CONTAINER
+            .size_symm(Size::Pixels(self.size))
+            .padding(0.0)
+            .sense_drag(true)
+            .sense_scroll(true)
+            .focusable(true)
+            .uses_arrow_keys(true)
+            .accessibility_role(AccessKitRole::Slider)
+            .accessibility_numeric_value(*self.value as f64, self.min as f64, self.max as f64)
+            .accessibility_actions(AccessibilityActions::INCREMENT | AccessibilityActions::DECREMENT)
+            .key(KNOB);
+
+        let groove = DEFAULT
+            .size_symm(Size::Fill)
+            .padding(0.0)
+            .shape(Shape::Arc { start_angle: KNOB_START_ANGLE, end_angle: KNOB_END_ANGLE, width: arc_width })
+            .fill(ui.theme().border)
+            .absorbs_clicks(false);
+
+        let value_arc = groove
+            .shape(Shape::Arc { start_angle: arc_start, end_angle: arc_end, width: arc_width })
+            .fill(ui.theme().primary);
+
+        let dot = DEFAULT
+            .size_symm(Size::Pixels(self.size * 0.14))
+            .padding(0.0)
+            .position_symm(Pos::Center)
+            .anchor_symm(Anchor::Center)
+            .shape(Shape::Circle)
+            .fill(ui.theme().text_primary)
+            .absorbs_clicks(false);
+
+        ui.add(knob).nest(|| {
+            ui.add(groove);
+            ui.add(value_arc);
+            ui.add(dot);
+        });
+
+        return *self.value != initial_value;
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
     }
 }
 
@@ -749,15 +1344,16 @@ impl Component for TabContainer<'_> {
 }
 
 
-use crate::thread_future_2::{ThreadFuture, run_in_background};
+use crate::thread_future_2::{ThreadFuture, Progress, run_in_background_with_progress};
 use std::sync::Arc;
 use std::task::Poll;
 
 pub struct AsyncButton<T>
 where T: Send + 'static {
-    async_function: Arc<dyn Fn() -> T + Send + Sync + 'static>,
+    async_function: Arc<dyn Fn(&Progress) -> T + Send + Sync + 'static>,
     idle_text: &'static str,
     loading_text: &'static str,
+    show_progress: bool,
     key: Option<ComponentKey<Self>>,
 }
 
@@ -765,10 +1361,25 @@ impl<T> AsyncButton<T>
 where T: Send + 'static {
     pub fn new<F>(function: F, idle_text: &'static str, loading_text: &'static str) -> Self
     where F: Fn() -> T + Send + Sync + 'static {
+        Self {
+            async_function: Arc::new(move |_: &Progress| function()),
+            idle_text,
+            loading_text,
+            show_progress: false,
+            key: None,
+        }
+    }
+
+    /// Like [`AsyncButton::new()`], but `function` can report its progress, which is shown in a [`ProgressBar`] under the button while it runs.
+    ///
+    /// If the function doesn't report anything, the progress bar is indeterminate.
+    pub fn with_progress<F>(function: F, idle_text: &'static str, loading_text: &'static str) -> Self
+    where F: Fn(&Progress) -> T + Send + Sync + 'static {
         Self {
             async_function: Arc::new(function),
             idle_text,
             loading_text,
+            show_progress: true,
             key: None,
         }
     }
@@ -791,6 +1402,7 @@ where T: Send + Sync + 'static {
         let clickable: bool;
         let button_text: &'static str;
         let result: Poll<T>;
+        let mut progress = None;
     
         match state.as_ref().map(|f| f.poll()) {
             None => {
@@ -802,6 +1414,7 @@ where T: Send + Sync + 'static {
                 button_text = self.loading_text;
                 clickable = false;
                 result = Poll::Pending;
+                progress = state.as_ref().map(|f| f.progress());
             }
             Some(Poll::Ready(val)) => {
                 button_text = self.idle_text;
@@ -813,13 +1426,27 @@ where T: Send + Sync + 'static {
     
         let button = BUTTON.static_text(button_text).key(ASYNC_BUTTON);
 
-        ui.add(button);
+        match progress {
+            Some(progress) if self.show_progress => {
+                let bar = match progress {
+                    Some(fraction) => ProgressBar::new(fraction),
+                    None => ProgressBar::indeterminate(),
+                };
+                ui.add(V_STACK.stack_spacing(5.0)).nest(|| {
+                    ui.add(button);
+                    ui.add_component(bar.width(Size::Fill));
+                });
+            }
+            _ => {
+                ui.add(button);
+            }
+        }
     
         if clickable && ui.is_clicked(ASYNC_BUTTON) {
             let waker = ui.ui_waker_safe();
             let func = Arc::clone(&self.async_function);
-            *state = Some(run_in_background(
-                move || func(),
+            *state = Some(run_in_background_with_progress(
+                move |progress| func(progress),
                 move || waker.set_update_needed(),
             ));
         }
@@ -832,31 +1459,227 @@ where T: Send + Sync + 'static {
     }
 }
 
+const PROGRESS_BAR_HEIGHT: f32 = 8.0;
+// Width of the indeterminate stripe, as a fraction of the bar.
+const PROGRESS_BAR_STRIPE: f32 = 0.3;
+const PROGRESS_BAR_PERIOD: f32 = 1.5;
+const SPINNER_PERIOD: f32 = 1.0;
 
-pub struct ReorderStack {
-    pub key: ComponentKey<Self>,
+// In reduced motion mode, indeterminate indicators pulse in place instead of moving.
+fn reduced_motion_pulse(time: f32, period: f32) -> f32 {
+    return 0.7 + 0.3 * (time / period * 2.0 * std::f32::consts::PI).cos();
 }
-impl ReorderStack {
-    #[node_key] const STACK: NodeKey;
-    #[node_key] const SPACER: NodeKey;
+
+/// A horizontal bar showing how far along a task is.
+///
+/// An indeterminate bar shows a stripe moving back and forth instead, or a pulsing bar in [reduced motion mode](Ui::set_reduced_motion). It only keeps the [`Ui`] updating while it's on screen.
+pub struct ProgressBar {
+    /// From 0.0 to 1.0, or `None` for an indeterminate bar.
+    pub progress: Option<f32>,
+    pub width: Size,
+    pub key: Option<ComponentKey<Self>>,
 }
 
-impl Component for ReorderStack {
-    type AddResult = UiParent;
-    type ComponentOutput = Option<(usize, usize)>;
-    type State = ();
+impl ProgressBar {
+    pub fn new(progress: f32) -> Self {
+        Self {
+            progress: Some(progress),
+            width: Size::Pixels(200.0),
+            key: None,
+        }
+    }
 
-    fn add_to_ui(&mut self, ui: &mut Ui, _state: &mut Self::State) -> Self::AddResult {
-    
-        let stack = V_STACK
-            .animate_position(true)
-            .size(Size::Pixels(100.0), Size::Fill)
-            .position_y(Pos::Start)
-            .stack_arrange(Arrange::Start)
-            .sense_drag_drop_target(true)
-            .key(Self::STACK);
-        
-        return ui.add(stack);
+    pub fn indeterminate() -> Self {
+        Self {
+            progress: None,
+            width: Size::Pixels(200.0),
+            key: None,
+        }
+    }
+
+    pub fn width(mut self, width: Size) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+}
+
+impl Component for ProgressBar {
+    type AddResult = ();
+    type ComponentOutput = ();
+    /// When the indeterminate animation started.
+    type State = Option<std::time::Instant>;
+
+    fn add_to_ui(&mut self, ui: &mut Ui, state: &mut Self::State) {
+        #[node_key] const PROGRESS_BAR: NodeKey;
+
+        // Nodes that haven't been laid out yet count as on screen, so that the animation can start.
+        let on_screen = ui.get_node(PROGRESS_BAR).is_none_or(|n| n.is_on_screen());
+
+        let shape = Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: PROGRESS_BAR_HEIGHT / 2.0 };
+
+        let mut track = PANEL
+            .size_x(self.width)
+            .size_y(Size::Pixels(PROGRESS_BAR_HEIGHT))
+            .padding(0.0)
+            .shape(shape)
+            .fill(ui.theme().muted_background)
+            .clip_children(true)
+            .accessibility_role(AccessKitRole::ProgressIndicator)
+            .key(PROGRESS_BAR);
+
+        let mut bar = PANEL
+            .size_y(Size::Fill)
+            .padding(0.0)
+            .shape(shape)
+            .fill(ui.theme().primary)
+            .absorbs_clicks(false);
+
+        match self.progress {
+            Some(progress) => {
+                *state = None;
+                let progress = progress.clamp(0.0, 1.0);
+                track = track.accessibility_numeric_value(progress as f64, 0.0, 1.0);
+                bar = bar.size_x(Size::Frac(progress));
+            }
+            None => {
+                let start = *state.get_or_insert_with(std::time::Instant::now);
+                let time = start.elapsed().as_secs_f32();
+                track = track.sense_time(on_screen);
+                if ui.reduced_motion() {
+                    bar = bar
+                        .size_x(Size::Fill)
+                        .alpha(reduced_motion_pulse(time, PROGRESS_BAR_PERIOD));
+                } else {
+                    let phase = (time / PROGRESS_BAR_PERIOD).fract();
+                    // Back and forth
+                    let t = 1.0 - (2.0 * phase - 1.0).abs();
+                    bar = bar
+                        .size_x(Size::Frac(PROGRESS_BAR_STRIPE))
+                        .free_placement(true)
+                        .position_x(Pos::Frac(t * (1.0 - PROGRESS_BAR_STRIPE)));
+                }
+            }
+        }
+
+        ui.add(track).nest(|| {
+            ui.add(bar);
+        });
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
+    }
+}
+
+/// A spinning arc, for tasks whose progress isn't known.
+///
+/// In [reduced motion mode](Ui::set_reduced_motion), it's a pulsing ring instead.
+///
+/// It only keeps the [`Ui`] updating while it's on screen.
+pub struct Spinner {
+    /// Diameter in pixels.
+    pub size: f32,
+    pub key: Option<ComponentKey<Self>>,
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Self {
+            size: 24.0,
+            key: None,
+        }
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for Spinner {
+    type AddResult = ();
+    type ComponentOutput = ();
+    /// When the animation started.
+    type State = Option<std::time::Instant>;
+
+    fn add_to_ui(&mut self, ui: &mut Ui, state: &mut Self::State) {
+        use std::f32::consts::PI;
+
+        #[node_key] const SPINNER: NodeKey;
+
+        let on_screen = ui.get_node(SPINNER).is_none_or(|n| n.is_on_screen());
+
+        let time = state.get_or_insert_with(std::time::Instant::now).elapsed().as_secs_f32();
+        let width = self.size * 0.12;
+
+        let (shape, alpha) = if ui.reduced_motion() {
+            (Shape::Ring { width }, reduced_motion_pulse(time, SPINNER_PERIOD))
+        } else {
+            let start_angle = (time / SPINNER_PERIOD * 2.0 * PI).rem_euclid(2.0 * PI);
+            // The arc also grows and shrinks, at a different speed than the rotation.
+            let length = PI * (1.0 + 0.5 * (time * PI / SPINNER_PERIOD).sin());
+            (Shape::Arc { start_angle, end_angle: start_angle + length, width }, 1.0)
+        };
+
+        let spinner = DEFAULT
+            .size_symm(Size::Pixels(self.size))
+            .padding(0.0)
+            .shape(shape)
+            .alpha(alpha)
+            .fill(ui.theme().primary)
+            .sense_time(on_screen)
+            .accessibility_role(AccessKitRole::ProgressIndicator)
+            .key(SPINNER);
+
+        ui.add(spinner);
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
+    }
+}
+
+
+pub struct ReorderStack {
+    pub key: ComponentKey<Self>,
+}
+impl ReorderStack {
+    #[node_key] const STACK: NodeKey;
+    #[node_key] const SPACER: NodeKey;
+}
+
+impl Component for ReorderStack {
+    type AddResult = UiParent;
+    type ComponentOutput = Option<(usize, usize)>;
+    type State = ();
+
+    fn add_to_ui(&mut self, ui: &mut Ui, _state: &mut Self::State) -> Self::AddResult {
+    
+        let stack = V_STACK
+            .animate_position(true)
+            .size(Size::Pixels(100.0), Size::Fill)
+            .position_y(Pos::Start)
+            .stack_arrange(Arrange::Start)
+            .sense_drag_drop_target(true)
+            .key(Self::STACK);
+        
+        return ui.add(stack);
     }
 
     fn component_key(&self) -> Option<ComponentKey<Self>> {
@@ -924,3 +1747,1366 @@ impl Component for ReorderStack {
         None
     }
 }
+
+#[derive(Default)]
+pub struct ModalState {
+    was_open: bool,
+    previous_focus: Option<Id>,
+}
+
+/// A modal dialog.
+///
+/// While `open` is true, a full-screen backdrop dims the rest of the Ui and absorbs all mouse events, and the Tab navigation is trapped inside the dialog.
+/// Pressing Escape (or clicking the backdrop, if enabled) sets `open` to false. When the dialog closes, the focus goes back to the node that had it before opening.
+///
+/// The component should be added every frame, even when closed, so that it can restore the focus.
+///
+/// ```no_run
+/// # use keru::*; use keru::node_library::*; let mut ui: Ui = unimplemented!();
+/// # let mut open = true;
+/// if let Some(dialog) = ui.add_component(Modal::new(&mut open)) {
+///     dialog.nest(|| {
+///         ui.static_label("Are you sure?");
+///     });
+/// }
+/// ```
+pub struct Modal<'a> {
+    pub open: &'a mut bool,
+    pub close_on_backdrop_click: bool,
+    pub key: Option<ComponentKey<Self>>,
+}
+
+impl<'a> Modal<'a> {
+    pub fn new(open: &'a mut bool) -> Self {
+        Self { open, close_on_backdrop_click: true, key: None }
+    }
+
+    pub fn close_on_backdrop_click(mut self, value: bool) -> Self {
+        self.close_on_backdrop_click = value;
+        self
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+}
+
+impl Component for Modal<'_> {
+    type AddResult = Option<UiParent>;
+    type ComponentOutput = ();
+    type State = ModalState;
+
+    fn add_to_ui(&mut self, ui: &mut Ui, state: &mut Self::State) -> Self::AddResult {
+        use winit::keyboard::{Key, NamedKey};
+
+        #[node_key] const MODAL_BACKDROP: NodeKey;
+        #[node_key] const MODAL_DIALOG: NodeKey;
+
+        if *self.open {
+            if ui.key_input().key_pressed(&Key::Named(NamedKey::Escape)) {
+                *self.open = false;
+            }
+            if self.close_on_backdrop_click && ui.is_clicked(MODAL_BACKDROP) {
+                *self.open = false;
+            }
+        }
+
+        // Just closed, either from inside or from outside: give the focus back.
+        if state.was_open && ! *self.open {
+            state.was_open = false;
+            if let Some(i) = state.previous_focus.take().and_then(|id| ui.sys.nodes.get_by_id(id)) {
+                let show_indicator = ui.sys.show_focus_indicator;
+                ui.set_focus_node(i, show_indicator);
+            }
+        }
+
+        if ! *self.open {
+            return None;
+        }
+
+        let just_opened = ! state.was_open;
+        if just_opened {
+            state.was_open = true;
+            state.previous_focus = ui.sys.focused;
+        }
+
+        let backdrop = PANEL
+            .size_symm(Size::Fill)
+            .padding(0.0)
+            .shape(Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 0.0 })
+            .color(Color::new(0.0, 0.0, 0.0, 0.55))
+            .absorbs_clicks(true)
+            .sense_click(true)
+            .click_animation(false)
+            .z_index(1000.0)
+            .fade()
+            .key(MODAL_BACKDROP);
+
+        let dialog = PANEL
+            .stack(Axis::Y, Arrange::Start, 10.0)
+            .fill(ui.theme().surface)
+            .absorbs_clicks(true)
+            .trap_focus(true)
+            .accessibility_role(AccessKitRole::Dialog)
+            .key(MODAL_DIALOG);
+
+        let dialog_parent = ui.jump_to_root().nest(|| {
+            ui.add(backdrop).nest(|| {
+                ui.add(dialog)
+            })
+        });
+
+        // Move the focus away from whatever is under the backdrop, so that Space/Enter can't activate it. The next Tab will land inside the dialog.
+        if just_opened && let Some(i) = ui.sys.nodes.get_with_key_scope(MODAL_DIALOG) {
+            ui.set_focus_node(i, false);
+        }
+
+        return Some(dialog_parent);
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
+    }
+}
+
+// A header that toggles a section, followed by the section's body if it's open.
+fn add_collapsible_section(ui: &mut Ui, title: &str, open: bool, header_key: NodeKey, body_key: NodeKey) -> Option<UiParent> {
+    let header = BUTTON
+        .stack(Axis::X, Arrange::Start, 6.0)
+        .size_x(Size::Fill)
+        .accessibility_expanded(open)
+        .key(header_key);
+
+    let arrow = TEXT
+        .static_text(if open { "▾" } else { "▸" })
+        .size_x(Size::Pixels(12.0))
+        .absorbs_clicks(false);
+
+    let title = TEXT
+        .text(title)
+        .absorbs_clicks(false);
+
+    let body = V_STACK
+        .size_x(Size::Fill)
+        .grow_shrink(Axis::Y, Pos::Start)
+        .animate_position(true)
+        .clip_children_y(true)
+        .key(body_key);
+
+    ui.add(header).nest(|| {
+        ui.add(arrow);
+        ui.add(title);
+    });
+
+    return open.then(|| ui.add(body));
+}
+
+#[derive(Default)]
+pub struct CollapsibleState {
+    open: Option<bool>,
+}
+
+/// A section with a clickable header, and a body that grows open and shrinks closed.
+///
+/// The body's content keeps its state while it's closed.
+///
+/// ```no_run
+/// # use keru::*; let mut ui: Ui = unimplemented!();
+/// if let Some(body) = ui.add_component(Collapsible::new("Advanced")) {
+///     body.nest(|| {
+///         ui.static_label("Hidden by default");
+///     });
+/// }
+/// ```
+pub struct Collapsible<'a> {
+    pub title: &'a str,
+    pub default_open: bool,
+    pub key: Option<ComponentKey<Self>>,
+}
+
+impl<'a> Collapsible<'a> {
+    pub fn new(title: &'a str) -> Self {
+        Self { title, default_open: false, key: None }
+    }
+
+    /// Whether the section starts open. Ignored after the first frame.
+    pub fn default_open(mut self, value: bool) -> Self {
+        self.default_open = value;
+        self
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+}
+
+impl Component for Collapsible<'_> {
+    /// The body, if the section is open.
+    type AddResult = Option<UiParent>;
+    type ComponentOutput = ();
+    type State = CollapsibleState;
+
+    fn add_to_ui(&mut self, ui: &mut Ui, state: &mut Self::State) -> Self::AddResult {
+        #[node_key] const COLLAPSIBLE_HEADER: NodeKey;
+        #[node_key] const COLLAPSIBLE_BODY: NodeKey;
+
+        let open = state.open.get_or_insert(self.default_open);
+        if ui.is_clicked(COLLAPSIBLE_HEADER) {
+            *open = ! *open;
+        }
+        let open = *open;
+
+        let container = V_STACK
+            .size_x(Size::Fill)
+            .stack_spacing(4.0)
+            .children_can_hide(true);
+
+        return ui.add(container).nest(|| {
+            add_collapsible_section(ui, self.title, open, COLLAPSIBLE_HEADER, COLLAPSIBLE_BODY)
+        });
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
+    }
+}
+
+#[derive(Default)]
+pub struct AccordionState {
+    open: Option<usize>,
+    initialized: bool,
+}
+
+/// A list of [`Collapsible`]-like sections where only one can be open at a time.
+///
+/// `add_content` is called with the index of the open section, to add its content.
+///
+/// ```no_run
+/// # use keru::*; let mut ui: Ui = unimplemented!();
+/// let titles = ["General", "Audio", "Video"];
+/// ui.add_component(Accordion::new(&titles, |ui, section| {
+///     ui.static_label(["General settings", "Audio settings", "Video settings"][section]);
+/// }));
+/// ```
+pub struct Accordion<'a, F: FnMut(&mut Ui, usize)> {
+    pub titles: &'a [&'a str],
+    pub add_content: F,
+    pub default_open: Option<usize>,
+    pub key: Option<ComponentKey<Self>>,
+}
+
+impl<'a, F: FnMut(&mut Ui, usize)> Accordion<'a, F> {
+    pub fn new(titles: &'a [&'a str], add_content: F) -> Self {
+        Self { titles, add_content, default_open: None, key: None }
+    }
+
+    /// The section that starts open. Ignored after the first frame.
+    pub fn default_open(mut self, section: Option<usize>) -> Self {
+        self.default_open = section;
+        self
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+}
+
+impl<F: FnMut(&mut Ui, usize)> Component for Accordion<'_, F> {
+    /// The index of the open section, if any.
+    type AddResult = Option<usize>;
+    type ComponentOutput = ();
+    type State = AccordionState;
+
+    fn add_to_ui(&mut self, ui: &mut Ui, state: &mut Self::State) -> Self::AddResult {
+        #[node_key] const ACCORDION_HEADER: NodeKey;
+        #[node_key] const ACCORDION_BODY: NodeKey;
+
+        if ! state.initialized {
+            state.open = self.default_open;
+            state.initialized = true;
+        }
+        for i in 0..self.titles.len() {
+            if ui.is_clicked(ACCORDION_HEADER.sibling(i)) {
+                state.open = if state.open == Some(i) { None } else { Some(i) };
+            }
+        }
+        state.open = state.open.filter(|&i| i < self.titles.len());
+
+        let container = V_STACK
+            .size_x(Size::Fill)
+            .stack_spacing(4.0)
+            .children_can_hide(true);
+
+        ui.add(container).nest(|| {
+            for (i, title) in self.titles.iter().enumerate() {
+                let open = state.open == Some(i);
+                if let Some(body) = add_collapsible_section(ui, title, open, ACCORDION_HEADER.sibling(i), ACCORDION_BODY.sibling(i)) {
+                    body.nest(|| (self.add_content)(ui, i));
+                }
+            }
+        });
+
+        return state.open;
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
+    }
+}
+
+/// A numeric type that can be edited with a [`NumberInput`].
+pub trait NumberInputValue: Copy {
+    /// If true, values are rounded to whole numbers and shown without decimals.
+    const IS_INTEGER: bool;
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+}
+
+impl NumberInputValue for f64 {
+    const IS_INTEGER: bool = false;
+    fn to_f64(self) -> f64 { self }
+    fn from_f64(value: f64) -> Self { value }
+}
+
+impl NumberInputValue for f32 {
+    const IS_INTEGER: bool = false;
+    fn to_f64(self) -> f64 { self as f64 }
+    fn from_f64(value: f64) -> Self { value as f32 }
+}
+
+macro_rules! impl_number_input_value_for_integers {
+    ($($t:ty),*) => {$(
+        impl NumberInputValue for $t {
+            const IS_INTEGER: bool = true;
+            fn to_f64(self) -> f64 { self as f64 }
+            // `as` saturates at the type's bounds.
+            fn from_f64(value: f64) -> Self { value.round() as $t }
+        }
+    )*};
+}
+impl_number_input_value_for_integers!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[derive(Default)]
+pub struct NumberInputState {
+    text: String,
+    invalid: bool,
+    was_editing: bool,
+    last_value: Option<f64>,
+    scrub_value: Option<f64>,
+}
+
+/// A text box for editing a number, with increment and decrement buttons.
+///
+/// The value can also be changed by dragging the label horizontally (hold Shift for finer steps), or with the up and down arrow keys while the text box is focused.
+///
+/// Text that can't be parsed or that is out of range is kept as it is and shown with the theme's error color, and the value is only updated once the text is valid again.
+///
+/// ```no_run
+/// # use keru::*; let mut ui: Ui = unimplemented!();
+/// # let mut gain: f64 = 0.0;
+/// ui.add_component(NumberInput::new(&mut gain).range(-60.0, 12.0).step(0.5).precision(1).suffix(" dB").label("Gain"));
+/// ```
+pub struct NumberInput<'a, T: NumberInputValue> {
+    pub value: &'a mut T,
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+    pub precision: usize,
+    pub suffix: &'a str,
+    pub label: Option<&'a str>,
+    pub key: Option<ComponentKey<Self>>,
+}
+
+impl<'a, T: NumberInputValue> NumberInput<'a, T> {
+    pub fn new(value: &'a mut T) -> Self {
+        Self {
+            value,
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            step: 1.0,
+            precision: 2,
+            suffix: "",
+            label: None,
+            key: None,
+        }
+    }
+
+    /// If `min` is greater than `max`, they're swapped.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        if min.is_nan() || max.is_nan() {
+            log::warn!("NumberInput range can't be NaN, keeping the previous range");
+            return self;
+        }
+        self.min = min.min(max);
+        self.max = max.max(min);
+        self
+    }
+
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Number of decimals shown. Ignored for integer types.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Text shown after the number, like a unit. It's optional when typing.
+    pub fn suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Text shown before the input. Dragging it horizontally changes the value.
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    fn format(&self, value: f64) -> String {
+        let precision = if T::IS_INTEGER { 0 } else { self.precision };
+        return format!("{:.*}{}", precision, value, self.suffix);
+    }
+
+    fn parse(&self, text: &str) -> Option<f64> {
+        let text = text.trim();
+        let text = text.strip_suffix(self.suffix.trim()).unwrap_or(text).trim();
+        let value = text.parse::<f64>().ok()?;
+        return value.is_finite().then_some(value);
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        let value = value.clamp(self.min, self.max);
+        return T::from_f64(value).to_f64();
+    }
+}
+
+impl<T: NumberInputValue> Component for NumberInput<'_, T> {
+    /// `true` if the value was changed this frame.
+    type AddResult = bool;
+    type ComponentOutput = ();
+    type State = NumberInputState;
+
+    fn add_to_ui(&mut self, ui: &mut Ui, state: &mut Self::State) -> Self::AddResult {
+        use winit::keyboard::{Key, NamedKey};
+
+        #[node_key] const NUMBER_INPUT_SCRUB: NodeKey;
+        #[node_key] const NUMBER_INPUT_DECREMENT: NodeKey;
+        #[node_key] const NUMBER_INPUT_EDIT: NodeKey;
+        #[node_key] const NUMBER_INPUT_INCREMENT: NodeKey;
+
+        let initial_value = self.value.to_f64();
+        let mut value = initial_value;
+        // Set when the value changed from anything other than typing, so the text has to be regenerated.
+        let mut reformat = state.last_value != Some(initial_value);
+
+        let fine = ui.key_input().key_mods().shift_key();
+        let step = if fine && ! T::IS_INTEGER { self.step * 0.1 } else { self.step };
+
+        let editing = ui.sys.focused == Some(NUMBER_INPUT_EDIT.id_with_key_scope());
+
+        // Typing
+        let typed = ui.get_node(NUMBER_INPUT_EDIT).and_then(|n| n.text_edit_changed().map(str::to_string));
+        if let Some(typed) = typed {
+            match self.parse(&typed) {
+                Some(parsed) if parsed >= self.min && parsed <= self.max => {
+                    value = T::from_f64(parsed).to_f64();
+                    state.invalid = false;
+                }
+                _ => state.invalid = true,
+            }
+        }
+
+        // Committing with Enter or by moving the focus away cleans up the text.
+        let committed = (editing && ui.key_input().key_pressed(&Key::Named(NamedKey::Enter)))
+            || (state.was_editing && ! editing);
+        if committed && ! state.invalid {
+            reformat = true;
+        }
+        state.was_editing = editing;
+
+        // Buttons, arrows and screen reader actions
+        let mut steps = 0.0;
+        if ui.is_clicked(NUMBER_INPUT_INCREMENT) || ui.accesskit_action(NUMBER_INPUT_EDIT, AccessKitAction::Increment) {
+            steps += 1.0;
+        }
+        if ui.is_clicked(NUMBER_INPUT_DECREMENT) || ui.accesskit_action(NUMBER_INPUT_EDIT, AccessKitAction::Decrement) {
+            steps -= 1.0;
+        }
+        if editing {
+            if ui.key_input().key_pressed_or_repeated(&Key::Named(NamedKey::ArrowUp)) {
+                steps += 1.0;
+            }
+            if ui.key_input().key_pressed_or_repeated(&Key::Named(NamedKey::ArrowDown)) {
+                steps -= 1.0;
+            }
+        }
+        if steps != 0.0 {
+            value = self.clamp(value + steps * step);
+            reformat = true;
+        }
+
+        // Scrubbing. Accumulate the unrounded value, so that slow drags still move integers.
+        if let Some(drag) = ui.is_dragged(NUMBER_INPUT_SCRUB) {
+            let pixels_per_step = if fine { 20.0 } else { 4.0 };
+            let scrub = state.scrub_value.unwrap_or(value) + drag.absolute_delta.x as f64 / pixels_per_step * self.step;
+            let scrub = scrub.clamp(self.min, self.max);
+            state.scrub_value = Some(scrub);
+            value = self.clamp(scrub);
+            reformat = true;
+        } else {
+            state.scrub_value = None;
+        }
+
+        if reformat {
+            state.text = self.format(value);
+            state.invalid = false;
+            // The declarative text is only applied when it changes, but the typed text might differ from it even if the formatted value didn't.
+            if let Some(edit) = ui.get_node_mut(NUMBER_INPUT_EDIT) {
+                edit.set_text(&state.text);
+            }
+        }
+
+        *self.value = T::from_f64(value);
+        let value = self.value.to_f64();
+        state.last_value = Some(value);
+
+        let scrub = LABEL
+            .text(self.label.unwrap_or("↔"))
+            .sense_drag(true)
+            .key(NUMBER_INPUT_SCRUB);
+
+        let decrement = BUTTON
+            .static_text("-")
+            .disabled(value <= self.min)
+            .key(NUMBER_INPUT_DECREMENT);
+
+        let increment = BUTTON
+            .static_text("+")
+            .disabled(value >= self.max)
+            .key(NUMBER_INPUT_INCREMENT);
+
+        let mut edit = TEXT_EDIT_LINE
+            .text(&state.text)
+            .size_x(Size::Pixels(110.0))
+            .accessibility_role(AccessKitRole::SpinButton)
+            .accessibility_numeric_value(value, self.min, self.max)
+            .accessibility_actions(AccessibilityActions::INCREMENT | AccessibilityActions::DECREMENT)
+            .key(NUMBER_INPUT_EDIT);
+        if state.invalid {
+            edit = edit.stroke(2.0).stroke_fill(ui.theme().error);
+        }
+
+        ui.add(H_STACK.stack_spacing(5.0)).nest(|| {
+            ui.add(scrub);
+            ui.add(decrement);
+            ui.add(edit);
+            ui.add(increment);
+        });
+
+        return value != initial_value;
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
+    }
+}
+
+/// Sort direction for a [`Table`] column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// How the rows of a [`Table`] can be selected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RowSelection {
+    None,
+    /// Clicking a row selects it.
+    Single,
+    /// Like `Single`, but Ctrl+click toggles a row and Shift+click selects a range.
+    Multiple,
+}
+
+/// A column of a [`Table`].
+#[derive(Clone, Copy, Debug)]
+pub struct TableColumn<'a> {
+    pub title: &'a str,
+    /// Initial width in pixels. The user can resize the column afterwards.
+    pub width: f32,
+    pub sortable: bool,
+}
+
+impl<'a> TableColumn<'a> {
+    pub fn new(title: &'a str, width: f32) -> Self {
+        Self { title, width, sortable: true }
+    }
+
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// The state of a [`Table`]: column widths and order, sorting and selection.
+///
+/// Column indices always refer to the position of the column in the slice passed to [`Table::new`], regardless of how the user reordered them.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableState {
+    /// Width of each column, in pixels.
+    pub column_widths: Vec<f32>,
+    /// The column indices, in the order that they are shown.
+    pub column_order: Vec<usize>,
+    /// The column that the rows should be sorted by.
+    ///
+    /// The table doesn't own the data, so it doesn't sort anything by itself: the app should read this and pass the rows in the right order.
+    pub sort: Option<(usize, SortDirection)>,
+    /// Indices of the selected rows.
+    pub selected_rows: Vec<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    selection_anchor: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dragged_column: Option<usize>,
+}
+
+impl TableState {
+    pub fn is_row_selected(&self, row: usize) -> bool {
+        self.selected_rows.contains(&row)
+    }
+}
+
+/// What happened in a [`Table`] this frame.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TableResponse {
+    /// The sort column or direction changed. The selection is cleared when this happens, since the row indices change meaning.
+    pub sort_changed: bool,
+    pub selection_changed: bool,
+    pub clicked_row: Option<usize>,
+}
+
+/// A table with a sticky header, resizable and reorderable columns, click-to-sort headers and row selection.
+///
+/// Only the rows that are scrolled into view are added to the `Ui`, so tables with many rows stay cheap. For this to work, all rows have the same height.
+///
+/// The `cell` closure is called with the row and column index for each visible cell, and should add the cell's content.
+///
+/// ```no_run
+/// # use keru::*; let mut ui: Ui = unimplemented!();
+/// # let mut state = TableState::default();
+/// let names = ["Alice", "Bob", "Carol"];
+/// let columns = [TableColumn::new("#", 50.0), TableColumn::new("Name", 200.0)];
+/// ui.add_component(Table::new(&mut state, &columns, names.len(), |ui, row, column| {
+///     match column {
+///         0 => { ui.label(&row.to_string()); },
+///         _ => { ui.static_label(names[row]); },
+///     }
+/// }));
+/// ```
+pub struct Table<'a, F: FnMut(&mut Ui, usize, usize)> {
+    pub state: &'a mut TableState,
+    pub columns: &'a [TableColumn<'a>],
+    pub n_rows: usize,
+    pub row_height: f32,
+    pub selection: RowSelection,
+    pub cell: F,
+    pub key: Option<ComponentKey<Self>>,
+}
+
+impl<'a, F: FnMut(&mut Ui, usize, usize)> Table<'a, F> {
+    pub fn new(state: &'a mut TableState, columns: &'a [TableColumn<'a>], n_rows: usize, cell: F) -> Self {
+        Self {
+            state,
+            columns,
+            n_rows,
+            row_height: 32.0,
+            selection: RowSelection::Multiple,
+            cell,
+            key: None,
+        }
+    }
+
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    pub fn selection(mut self, selection: RowSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+}
+
+const TABLE_MIN_COLUMN_WIDTH: f32 = 30.0;
+// Extra rows added above and below the visible ones, so that fast scrolling doesn't show empty space for a frame.
+const TABLE_OVERSCAN_ROWS: usize = 3;
+
+impl<F: FnMut(&mut Ui, usize, usize)> Component for Table<'_, F> {
+    type AddResult = TableResponse;
+    type ComponentOutput = ();
+    type State = ();
+
+    fn add_to_ui(&mut self, ui: &mut Ui, _state: &mut Self::State) -> Self::AddResult {
+        #[node_key] const TABLE_HEADER_CELL: NodeKey;
+        #[node_key] const TABLE_RESIZE_HANDLE: NodeKey;
+        #[node_key] const TABLE_BODY: NodeKey;
+        #[node_key] const TABLE_ROW: NodeKey;
+        #[node_key] const TABLE_CELL: NodeKey;
+
+        let Table { state, columns, n_rows, row_height, selection, cell, .. } = self;
+        let (n_rows, row_height, selection) = (*n_rows, *row_height, *selection);
+        let n_columns = columns.len();
+
+        if state.column_widths.len() != n_columns {
+            state.column_widths = columns.iter().map(|c| c.width).collect();
+        }
+        let order_is_valid = state.column_order.len() == n_columns && (0..n_columns).all(|c| state.column_order.contains(&c));
+        if ! order_is_valid {
+            state.column_order = (0..n_columns).collect();
+        }
+
+        let mut response = TableResponse::default();
+
+        // Resizing
+        for c in 0..n_columns {
+            if let Some(drag) = ui.is_dragged(TABLE_RESIZE_HANDLE.sibling(c)) {
+                state.column_widths[c] = (state.column_widths[c] + drag.absolute_delta.x).max(TABLE_MIN_COLUMN_WIDTH);
+            }
+        }
+
+        // Reordering
+        for c in 0..n_columns {
+            if let Some(drag) = ui.is_dragged(TABLE_HEADER_CELL.sibling(c)) && drag.total_drag_distance.length() > 4.0 {
+                state.dragged_column = Some(c);
+            }
+        }
+        let mut drop_target = None;
+        if let Some(src) = state.dragged_column {
+            let src_key = TABLE_HEADER_CELL.sibling(src);
+            for dest in 0..n_columns {
+                if dest == src {
+                    continue;
+                }
+                let dest_key = TABLE_HEADER_CELL.sibling(dest);
+                if ui.is_drag_released_onto(src_key, dest_key).is_some() {
+                    let from = state.column_order.iter().position(|&c| c == src).unwrap();
+                    let to = state.column_order.iter().position(|&c| c == dest).unwrap();
+                    let moved = state.column_order.remove(from);
+                    state.column_order.insert(to, moved);
+                } else if ui.is_drag_hovered_onto(src_key, dest_key).is_some() {
+                    drop_target = Some(dest);
+                }
+            }
+        }
+
+        // Sorting. A header that was just dragged around doesn't count as clicked.
+        for c in 0..n_columns {
+            if columns[c].sortable && state.dragged_column.is_none() && ui.is_click_released(TABLE_HEADER_CELL.sibling(c)) {
+                state.sort = match state.sort {
+                    Some((sorted, SortDirection::Ascending)) if sorted == c => Some((c, SortDirection::Descending)),
+                    Some((sorted, SortDirection::Descending)) if sorted == c => None,
+                    _ => Some((c, SortDirection::Ascending)),
+                };
+                state.selected_rows.clear();
+                state.selection_anchor = None;
+                response.sort_changed = true;
+            }
+        }
+
+        if let Some(src) = state.dragged_column && ui.is_drag_released(TABLE_HEADER_CELL.sibling(src)) {
+            state.dragged_column = None;
+        }
+
+        // Virtualization: only add the rows that are in view, using last frame's scroll.
+        let (scroll_y, visible_height) = match ui.get_node(TABLE_BODY) {
+            Some(body) => (body.scroll_offset().y, body.inner_size().y),
+            // Just a guess for the first frame.
+            None => (0.0, 1000.0),
+        };
+        let first_row = ((scroll_y / row_height).floor().max(0.0) as usize).saturating_sub(TABLE_OVERSCAN_ROWS).min(n_rows);
+        let last_row = (((scroll_y + visible_height) / row_height).ceil().max(0.0) as usize + TABLE_OVERSCAN_ROWS).min(n_rows);
+
+        // Selection
+        let ctrl = ui.key_input().key_mods().control_key();
+        let shift = ui.key_input().key_mods().shift_key();
+        for row in first_row..last_row {
+            if ! ui.is_clicked(TABLE_ROW.sibling(row)) {
+                continue;
+            }
+            response.clicked_row = Some(row);
+            match selection {
+                RowSelection::None => {}
+                RowSelection::Single => {
+                    state.selected_rows.clear();
+                    state.selected_rows.push(row);
+                    state.selection_anchor = Some(row);
+                    response.selection_changed = true;
+                }
+                RowSelection::Multiple => {
+                    if shift && let Some(anchor) = state.selection_anchor {
+                        state.selected_rows.clear();
+                        state.selected_rows.extend(anchor.min(row)..=anchor.max(row));
+                    } else if ctrl {
+                        if let Some(pos) = state.selected_rows.iter().position(|&r| r == row) {
+                            state.selected_rows.remove(pos);
+                        } else {
+                            state.selected_rows.push(row);
+                        }
+                        state.selection_anchor = Some(row);
+                    } else {
+                        state.selected_rows.clear();
+                        state.selected_rows.push(row);
+                        state.selection_anchor = Some(row);
+                    }
+                    response.selection_changed = true;
+                }
+            }
+        }
+
+        let header_fill = ui.theme().surface_alt;
+        let drop_target_stroke = ui.theme().primary;
+        let border = ui.theme().border;
+        let row_fill = ui.theme().surface;
+        let alt_row_fill = ui.theme().background;
+        let selected_row_fill = ui.theme().secondary;
+
+        let table = V_STACK
+            .stack_spacing(0.0)
+            .size_x(Size::FitContent)
+            .accessibility_role(AccessKitRole::Table);
+
+        let header_row = H_STACK
+            .stack_spacing(0.0)
+            .size_y(Size::FitContent)
+            .accessibility_role(AccessKitRole::Row);
+
+        let body = V_STACK
+            .stack_spacing(0.0)
+            .size(Size::FitContent, Size::Fill)
+            .scrollable_y(true)
+            .padding(0.0)
+            .key(TABLE_BODY);
+
+        let square = Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 0.0 };
+
+        with_arena(|a| {
+            ui.add(table).nest(|| {
+                ui.add(header_row).nest(|| {
+                    for &c in &state.column_order {
+                        let title = match state.sort {
+                            Some((sorted, SortDirection::Ascending)) if sorted == c => bumpalo::format!(in a, "{} ▲", columns[c].title),
+                            Some((sorted, SortDirection::Descending)) if sorted == c => bumpalo::format!(in a, "{} ▼", columns[c].title),
+                            _ => bumpalo::format!(in a, "{}", columns[c].title),
+                        };
+
+                        let mut header_cell = BUTTON
+                            .text(&title)
+                            .size(Size::Pixels(state.column_widths[c]), Size::Pixels(row_height))
+                            .padding_x(0.0)
+                            .shape(square)
+                            .fill(header_fill)
+                            .sense_drag(true)
+                            .sense_drag_drop_target(true)
+                            .accessibility_role(AccessKitRole::ColumnHeader)
+                            .key(TABLE_HEADER_CELL.sibling(c));
+                        if drop_target == Some(c) {
+                            header_cell = header_cell.stroke(2.0).stroke_fill(drop_target_stroke);
+                        }
+
+                        let resize_handle = PANEL
+                            .size(Size::Pixels(4.0), Size::Fill)
+                            .position_x(Pos::End)
+                            .padding(0.0)
+                            .shape(square)
+                            .fill(border)
+                            .sense_drag(true)
+                            // Absorb clicks, so that resizing a column doesn't also sort by it.
+                            .sense_click(true)
+                            .key(TABLE_RESIZE_HANDLE.sibling(c));
+
+                        ui.add(header_cell).nest(|| {
+                            ui.add(resize_handle);
+                        });
+                    }
+                });
+
+                ui.add(body).nest(|| {
+                    // Spacers stand in for the rows that are out of view, so that the scroll range stays right.
+                    if first_row > 0 {
+                        ui.add(SPACER.size_y(Size::Pixels(first_row as f32 * row_height)));
+                    }
+
+                    for row in first_row..last_row {
+                        let selected = state.is_row_selected(row);
+                        let fill = if selected {
+                            selected_row_fill
+                        } else if row % 2 == 0 {
+                            row_fill
+                        } else {
+                            alt_row_fill
+                        };
+
+                        let row_node = PANEL
+                            .stack(Axis::X, Arrange::Start, 0.0)
+                            .padding(0.0)
+                            .shape(square)
+                            .fill(fill)
+                            .sense_click(selection != RowSelection::None)
+                            .accessibility_role(AccessKitRole::Row)
+                            .accessibility_selected(selected)
+                            .key(TABLE_ROW.sibling(row));
+
+                        ui.add(row_node).nest(|| {
+                            for &c in &state.column_order {
+                                let cell_node = CONTAINER
+                                    .size(Size::Pixels(state.column_widths[c]), Size::Pixels(row_height))
+                                    .clip_children(true)
+                                    .accessibility_role(AccessKitRole::Cell)
+                                    .key(TABLE_CELL.sibling((row, c)));
+                                ui.add(cell_node).nest(|| {
+                                    cell(ui, row, c);
+                                });
+                            }
+                        });
+                    }
+
+                    if last_row < n_rows {
+                        ui.add(SPACER.size_y(Size::Pixels((n_rows - last_row) as f32 * row_height)));
+                    }
+                });
+            });
+        });
+
+        return response;
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
+    }
+}
+
+/// The data behind a [`TreeView`].
+///
+/// The tree view never stores the hierarchy itself: it asks for the children of an item only when the item is expanded, so they can be loaded lazily (for example, reading a directory when its folder is opened).
+pub trait TreeViewSource {
+    /// A stable identifier for an item. It's used for the items' node keys, so it should stay the same across frames.
+    type Id: Copy + Eq + std::hash::Hash + 'static;
+
+    /// Pushes the children of `parent` into `out`, or the top level items if `parent` is `None`.
+    ///
+    /// This is only called for expanded items that are shown.
+    fn children(&mut self, parent: Option<Self::Id>, out: &mut Vec<Self::Id>);
+
+    /// Returns `true` if the item can be expanded. This is called for every shown item, so it should be cheap and shouldn't need to load the children.
+    fn has_children(&mut self, id: Self::Id) -> bool;
+
+    /// Adds the content of an item's row, usually a label.
+    fn add_item(&mut self, ui: &mut Ui, id: Self::Id);
+
+    /// Returns `true` if `id` can be dropped into `new_parent`. `new_parent` is `None` for the top level.
+    ///
+    /// Dropping an item into itself or into one of its descendants is never allowed, regardless of this function.
+    fn can_reparent(&mut self, id: Self::Id, new_parent: Option<Self::Id>) -> bool {
+        let _ = (id, new_parent);
+        return true;
+    }
+}
+
+/// The state of a [`TreeView`]: which items are expanded and which are selected.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreeViewState<T> {
+    pub expanded: Vec<T>,
+    pub selected: Vec<T>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    selection_anchor: Option<T>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dragged: Option<T>,
+}
+
+impl<T> Default for TreeViewState<T> {
+    fn default() -> Self {
+        Self {
+            expanded: Vec::new(),
+            selected: Vec::new(),
+            selection_anchor: None,
+            dragged: None,
+        }
+    }
+}
+
+impl<T: PartialEq> TreeViewState<T> {
+    pub fn is_expanded(&self, id: &T) -> bool {
+        self.expanded.contains(id)
+    }
+
+    pub fn is_selected(&self, id: &T) -> bool {
+        self.selected.contains(id)
+    }
+
+    pub fn set_expanded(&mut self, id: T, expanded: bool) {
+        let pos = self.expanded.iter().position(|e| *e == id);
+        match (pos, expanded) {
+            (None, true) => self.expanded.push(id),
+            (Some(pos), false) => { self.expanded.swap_remove(pos); },
+            _ => {},
+        }
+    }
+}
+
+/// What happened in a [`TreeView`] this frame.
+#[derive(Clone, Copy, Debug)]
+pub struct TreeViewResponse<T> {
+    pub selection_changed: bool,
+    /// An item was dragged onto another item (`Some(new_parent)`) or onto the empty space below the items (`None`).
+    ///
+    /// The tree view doesn't own the data, so the app should do the actual move.
+    pub reparented: Option<(T, Option<T>)>,
+}
+
+impl<T> Default for TreeViewResponse<T> {
+    fn default() -> Self {
+        Self { selection_changed: false, reparented: None }
+    }
+}
+
+/// A hierarchical list of items that can be expanded and collapsed, such as a file explorer or a scene graph.
+///
+/// Clicking a row selects it, Ctrl+click and Shift+click extend the selection. When a row has focus, the Up and Down keys move between rows, Left collapses the item or goes to its parent, and Right expands it or goes to its first child.
+///
+/// Rows can be dragged onto other rows to reparent them, see [`TreeViewResponse::reparented`].
+pub struct TreeView<'a, S: TreeViewSource> {
+    pub state: &'a mut TreeViewState<S::Id>,
+    pub source: &'a mut S,
+    pub indent: f32,
+    pub multi_select: bool,
+    pub drag_to_reparent: bool,
+    pub key: Option<ComponentKey<Self>>,
+}
+
+impl<'a, S: TreeViewSource> TreeView<'a, S> {
+    pub fn new(state: &'a mut TreeViewState<S::Id>, source: &'a mut S) -> Self {
+        Self {
+            state,
+            source,
+            indent: 16.0,
+            multi_select: true,
+            drag_to_reparent: true,
+            key: None,
+        }
+    }
+
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    pub fn drag_to_reparent(mut self, drag_to_reparent: bool) -> Self {
+        self.drag_to_reparent = drag_to_reparent;
+        self
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+}
+
+struct TreeViewRow<T> {
+    id: T,
+    parent: Option<T>,
+    depth: usize,
+    has_children: bool,
+    expanded: bool,
+}
+
+// Lists the shown items in order, descending only into the expanded ones.
+fn flatten_tree<S: TreeViewSource>(source: &mut S, state: &TreeViewState<S::Id>, parent: Option<S::Id>, depth: usize, out: &mut Vec<TreeViewRow<S::Id>>) {
+    let mut children = Vec::new();
+    source.children(parent, &mut children);
+    for id in children {
+        let has_children = source.has_children(id);
+        let expanded = has_children && state.is_expanded(&id);
+        out.push(TreeViewRow { id, parent, depth, has_children, expanded });
+        if expanded {
+            flatten_tree(source, state, Some(id), depth + 1, out);
+        }
+    }
+}
+
+// Only looks at the shown rows: a collapsed item's descendants can't be drop targets anyway.
+fn tree_is_descendant<T: Copy + PartialEq>(rows: &[TreeViewRow<T>], id: T, ancestor: T) -> bool {
+    let mut current = Some(id);
+    while let Some(c) = current {
+        if c == ancestor {
+            return true;
+        }
+        current = rows.iter().find(|r| r.id == c).and_then(|r| r.parent);
+    }
+    return false;
+}
+
+impl<S: TreeViewSource> Component for TreeView<'_, S> {
+    type AddResult = TreeViewResponse<S::Id>;
+    type ComponentOutput = ();
+    type State = ();
+
+    fn add_to_ui(&mut self, ui: &mut Ui, _state: &mut Self::State) -> Self::AddResult {
+        use winit::keyboard::{Key, NamedKey};
+
+        #[node_key] const TREE_VIEW: NodeKey;
+        #[node_key] const TREE_ROW: NodeKey;
+        #[node_key] const TREE_CHEVRON: NodeKey;
+
+        let mut response = TreeViewResponse::default();
+
+        let mut rows = Vec::new();
+        flatten_tree(&mut *self.source, &*self.state, None, 0, &mut rows);
+
+        // Expanding and collapsing
+        for row in &rows {
+            if ! row.has_children {
+                continue;
+            }
+            let toggled = ui.is_clicked(TREE_CHEVRON.sibling(row.id));
+            let expand = ui.accesskit_action(TREE_ROW.sibling(row.id), AccessKitAction::Expand);
+            let collapse = ui.accesskit_action(TREE_ROW.sibling(row.id), AccessKitAction::Collapse);
+            if toggled || expand || collapse {
+                let expanded = (row.expanded && ! toggled && ! collapse) || (! row.expanded && (toggled || expand));
+                self.state.set_expanded(row.id, expanded);
+            }
+        }
+
+        // Selection
+        let ctrl = ui.key_input().key_mods().control_key();
+        let shift = ui.key_input().key_mods().shift_key();
+        for (n, row) in rows.iter().enumerate() {
+            if ui.is_clicked(TREE_ROW.sibling(row.id)) {
+                self.select(&rows, n, ctrl, shift);
+                response.selection_changed = true;
+            }
+        }
+
+        // Keyboard navigation
+        let focused = rows.iter().position(|row| ui.sys.focused == Some(TREE_ROW.sibling(row.id).id_with_key_scope()));
+        if let Some(n) = focused {
+            let pressed = |ui: &Ui, key: NamedKey| ui.key_input().key_pressed_or_repeated(&Key::Named(key));
+            let row = &rows[n];
+
+            let mut move_to = None;
+            if pressed(ui, NamedKey::ArrowUp) && n > 0 {
+                move_to = Some(n - 1);
+            }
+            if pressed(ui, NamedKey::ArrowDown) && n + 1 < rows.len() {
+                move_to = Some(n + 1);
+            }
+            if pressed(ui, NamedKey::ArrowLeft) {
+                if row.expanded {
+                    self.state.set_expanded(row.id, false);
+                } else if let Some(parent) = row.parent {
+                    move_to = rows.iter().position(|r| r.id == parent);
+                }
+            }
+            if pressed(ui, NamedKey::ArrowRight) && row.has_children {
+                if row.expanded {
+                    // The first child always comes right after an expanded item, unless it has no children after all.
+                    if n + 1 < rows.len() && rows[n + 1].parent == Some(row.id) {
+                        move_to = Some(n + 1);
+                    }
+                } else {
+                    self.state.set_expanded(row.id, true);
+                }
+            }
+
+            if let Some(m) = move_to {
+                if ! ctrl {
+                    self.select(&rows, m, false, shift);
+                    response.selection_changed = true;
+                }
+                if let Some(i) = ui.sys.nodes.get_with_key_scope(TREE_ROW.sibling(rows[m].id)) {
+                    ui.set_focus_node(i, true);
+                    ui.sys.scroll_node_into_view(i, 0.0, true);
+                }
+            }
+        }
+
+        // Drag to reparent
+        let mut drop_target = None;
+        if self.drag_to_reparent {
+            for row in &rows {
+                if let Some(drag) = ui.is_dragged(TREE_ROW.sibling(row.id)) && drag.total_drag_distance.length() > 4.0 {
+                    self.state.dragged = Some(row.id);
+                }
+            }
+
+            if let Some(src) = self.state.dragged {
+                let src_key = TREE_ROW.sibling(src);
+                let src_parent = rows.iter().find(|r| r.id == src).and_then(|r| r.parent);
+                // A drag over a row never falls through to the top level, even when the row can't take it.
+                let mut over_row = false;
+                for row in &rows {
+                    let dest_key = TREE_ROW.sibling(row.id);
+                    let released = ui.is_drag_released_onto(src_key, dest_key).is_some();
+                    let hovered = ui.is_drag_hovered_onto(src_key, dest_key).is_some();
+                    over_row |= released || hovered;
+
+                    // Dropping onto the current parent would do nothing, and dropping onto a descendant would create a cycle.
+                    if Some(row.id) == src_parent || tree_is_descendant(&rows, row.id, src) {
+                        continue;
+                    }
+                    if released {
+                        if self.source.can_reparent(src, Some(row.id)) {
+                            response.reparented = Some((src, Some(row.id)));
+                        }
+                    } else if hovered && self.source.can_reparent(src, Some(row.id)) {
+                        drop_target = Some(Some(row.id));
+                    }
+                }
+
+                // The empty space below the rows counts as the top level. Items that are already there are skipped, like for their parent row.
+                if ! over_row && src_parent.is_some() {
+                    if ui.is_drag_released_onto(src_key, TREE_VIEW).is_some() {
+                        if self.source.can_reparent(src, None) {
+                            response.reparented = Some((src, None));
+                        }
+                    } else if ui.is_drag_hovered_onto(src_key, TREE_VIEW).is_some() && self.source.can_reparent(src, None) {
+                        drop_target = Some(None);
+                    }
+                }
+
+                if ui.is_drag_released(src_key) {
+                    self.state.dragged = None;
+                }
+            }
+        }
+
+        // Input could have expanded or collapsed something.
+        rows.clear();
+        flatten_tree(&mut *self.source, &*self.state, None, 0, &mut rows);
+
+        let selected_fill = ui.theme().secondary;
+        let drop_target_stroke = ui.theme().primary;
+        let square = Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 0.0 };
+
+        let mut tree = V_STACK
+            .stack_spacing(0.0)
+            .size(Size::Fill, Size::Fill)
+            .padding(0.0)
+            .scrollable_y(true)
+            .sense_drag_drop_target(self.drag_to_reparent)
+            .accessibility_role(AccessKitRole::Tree)
+            .key(TREE_VIEW);
+        if drop_target == Some(None) {
+            tree = tree.stroke(2.0).stroke_fill(drop_target_stroke);
+        }
+
+        ui.add(tree).nest(|| {
+            for row in &rows {
+                let selected = self.state.is_selected(&row.id);
+
+                let mut row_node = CONTAINER
+                    .stack(Axis::X, Arrange::Start, 4.0)
+                    .size_x(Size::Fill)
+                    .padding_x(4.0)
+                    .padding_y(2.0)
+                    .shape(square)
+                    .sense_click(true)
+                    .sense_drag(self.drag_to_reparent)
+                    .sense_drag_drop_target(self.drag_to_reparent)
+                    .accessibility_role(AccessKitRole::TreeItem)
+                    .accessibility_selected(selected)
+                    .focusable(true)
+                    .uses_arrow_keys(true)
+                    .key(TREE_ROW.sibling(row.id));
+                if row.has_children {
+                    row_node = row_node.accessibility_expanded(row.expanded);
+                }
+                if selected {
+                    row_node = row_node.visible().fill(selected_fill);
+                }
+                if drop_target == Some(Some(row.id)) {
+                    row_node = row_node.visible().stroke(2.0).stroke_fill(drop_target_stroke);
+                }
+
+                let indent = CONTAINER
+                    .size(Size::Pixels(row.depth as f32 * self.indent), Size::Pixels(1.0))
+                    .padding(0.0);
+
+                let chevron = TEXT
+                    .static_text(if row.expanded { "▾" } else { "▸" })
+                    .size_x(Size::Pixels(12.0))
+                    .sense_click(true)
+                    // Expanding or collapsing a row shouldn't select it.
+                    .absorbs_clicks(true)
+                    .key(TREE_CHEVRON.sibling(row.id));
+                // Leaves get an empty slot of the same width, so that the content lines up.
+                let chevron_slot = CONTAINER
+                    .size(Size::Pixels(12.0), Size::Pixels(1.0))
+                    .padding(0.0);
+
+                ui.add(row_node).nest(|| {
+                    if row.depth > 0 {
+                        ui.add(indent);
+                    }
+                    if row.has_children {
+                        ui.add(chevron);
+                    } else {
+                        ui.add(chevron_slot);
+                    }
+                    self.source.add_item(ui, row.id);
+                });
+            }
+        });
+
+        return response;
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
+    }
+}
+
+impl<S: TreeViewSource> TreeView<'_, S> {
+    fn select(&mut self, rows: &[TreeViewRow<S::Id>], n: usize, ctrl: bool, shift: bool) {
+        let id = rows[n].id;
+        let state = &mut *self.state;
+
+        if self.multi_select && shift && let Some(anchor) = state.selection_anchor && let Some(a) = rows.iter().position(|r| r.id == anchor) {
+            state.selected.clear();
+            state.selected.extend(rows[a.min(n)..=a.max(n)].iter().map(|r| r.id));
+        } else if self.multi_select && ctrl {
+            if let Some(pos) = state.selected.iter().position(|s| *s == id) {
+                state.selected.remove(pos);
+            } else {
+                state.selected.push(id);
+            }
+            state.selection_anchor = Some(id);
+        } else {
+            state.selected.clear();
+            state.selected.push(id);
+            state.selection_anchor = Some(id);
+        }
+    }
+}
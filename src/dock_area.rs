@@ -0,0 +1,779 @@
+use crate as keru;
+use keru::*;
+use keru::node_library::*;
+use slab::Slab;
+
+/// A side of a tab group, where a new group can be split off.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DockSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl DockSide {
+    const ALL: [DockSide; 4] = [DockSide::Left, DockSide::Right, DockSide::Top, DockSide::Bottom];
+
+    // The axis of the split, and whether the new group goes after the old one.
+    fn axis_and_after(self) -> (Axis, bool) {
+        match self {
+            DockSide::Left => (Axis::X, false),
+            DockSide::Right => (Axis::X, true),
+            DockSide::Top => (Axis::Y, false),
+            DockSide::Bottom => (Axis::Y, true),
+        }
+    }
+}
+
+/// Identifies a tab group in a [`DockLayout`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DockGroup(usize);
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum DockPane<T> {
+    Split { axis: Axis, children: Vec<usize> },
+    Tabs { tabs: Vec<T>, active: usize },
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct DockEntry<T> {
+    pane: DockPane<T>,
+    // Relative to the siblings.
+    weight: f32,
+    parent: Option<usize>,
+}
+
+/// The layout of a [`DockArea`]: a tree of splits, with a group of tabs at each leaf.
+///
+/// The layout only stores the tab identifiers: the tabs' titles and content are provided by a [`DockTabViewer`].
+///
+/// With the `serde` feature, the layout can be serialized, so that the users' workspace can be restored in the next session.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DockLayout<T> {
+    panes: Slab<DockEntry<T>>,
+    root: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dragged: Option<T>,
+}
+
+impl<T: Copy + PartialEq> DockLayout<T> {
+    /// Create a layout with a single group containing `tabs`.
+    pub fn new(tabs: impl IntoIterator<Item = T>) -> Self {
+        let mut panes = Slab::with_capacity(8);
+        let root = panes.insert(DockEntry {
+            pane: DockPane::Tabs { tabs: tabs.into_iter().collect(), active: 0 },
+            weight: 1.0,
+            parent: None,
+        });
+        return Self { panes, root, dragged: None };
+    }
+
+    /// Returns all the tab groups in the layout.
+    pub fn groups(&self) -> impl Iterator<Item = DockGroup> + '_ {
+        self.panes.iter()
+            .filter(|(_, entry)| matches!(entry.pane, DockPane::Tabs { .. }))
+            .map(|(i, _)| DockGroup(i))
+    }
+
+    /// Returns the tabs in `group`, in order.
+    pub fn tabs(&self, group: DockGroup) -> &[T] {
+        match self.panes.get(group.0).map(|entry| &entry.pane) {
+            Some(DockPane::Tabs { tabs, .. }) => tabs,
+            _ => &[],
+        }
+    }
+
+    /// Returns the tab that is currently shown in `group`.
+    pub fn active_tab(&self, group: DockGroup) -> Option<T> {
+        match self.panes.get(group.0).map(|entry| &entry.pane) {
+            Some(DockPane::Tabs { tabs, active }) => tabs.get(*active).copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns the group that contains `tab`.
+    pub fn find_tab(&self, tab: T) -> Option<DockGroup> {
+        return self.find_group(tab).map(DockGroup);
+    }
+
+    /// Add `tab` at the end of `group` and show it. If `tab` is already in the layout, it's moved.
+    pub fn add_tab(&mut self, group: DockGroup, tab: T) {
+        if self.find_group(tab).is_some() {
+            self.move_tab(tab, group.0, None);
+        } else if let DockPane::Tabs { tabs, active } = &mut self.panes[group.0].pane {
+            tabs.push(tab);
+            *active = tabs.len() - 1;
+        }
+    }
+
+    /// Split a new group containing `tab` off the `side` of `group`, and return it. If `tab` is already in the layout, it's moved.
+    pub fn split(&mut self, group: DockGroup, side: DockSide, tab: T) -> DockGroup {
+        if self.find_group(tab).is_some() {
+            self.split_with_tab(tab, group.0, side);
+            return self.find_tab(tab).unwrap();
+        }
+        return DockGroup(self.split_pane(group.0, side, tab));
+    }
+
+    /// Remove `tab` from the layout. Its group is removed too if it becomes empty.
+    ///
+    /// Returns `false` if the tab wasn't in the layout.
+    pub fn remove_tab(&mut self, tab: T) -> bool {
+        let Some(group) = self.detach_tab(tab) else {
+            return false;
+        };
+        self.remove_group_if_empty(group);
+        return true;
+    }
+
+    /// Show `tab` in its group.
+    pub fn set_active_tab(&mut self, tab: T) {
+        if let Some(group) = self.find_group(tab)
+            && let DockPane::Tabs { tabs, active } = &mut self.panes[group].pane {
+            *active = tabs.iter().position(|t| *t == tab).unwrap();
+        }
+    }
+
+    fn find_group(&self, tab: T) -> Option<usize> {
+        return self.panes.iter()
+            .find(|(_, entry)| matches!(&entry.pane, DockPane::Tabs { tabs, .. } if tabs.contains(&tab)))
+            .map(|(i, _)| i);
+    }
+
+    // Removes the tab from its group without removing the group. Returns the group.
+    fn detach_tab(&mut self, tab: T) -> Option<usize> {
+        let group = self.find_group(tab)?;
+        let DockPane::Tabs { tabs, active } = &mut self.panes[group].pane else { unreachable!() };
+        let pos = tabs.iter().position(|t| *t == tab).unwrap();
+        tabs.remove(pos);
+        // Keep showing the same tab, or the next one if the active one was removed.
+        if *active > pos || *active >= tabs.len() {
+            *active = active.saturating_sub(1);
+        }
+        return Some(group);
+    }
+
+    fn remove_group_if_empty(&mut self, group: usize) {
+        if let DockPane::Tabs { tabs, .. } = &self.panes[group].pane && tabs.is_empty() {
+            self.remove_pane(group);
+        }
+    }
+
+    fn remove_pane(&mut self, i: usize) {
+        // The root group is kept even when it's empty, so that tabs can be dropped back into it.
+        let Some(parent) = self.panes[i].parent else {
+            return;
+        };
+        self.panes.remove(i);
+
+        let DockPane::Split { children, .. } = &mut self.panes[parent].pane else { unreachable!() };
+        children.retain(|&c| c != i);
+
+        // A split with a single child is useless, so the child takes its place.
+        if children.len() == 1 {
+            let child = children[0];
+            let grandparent = self.panes[parent].parent;
+            self.panes[child].weight = self.panes[parent].weight;
+            self.panes[child].parent = grandparent;
+            self.replace_child(grandparent, parent, child);
+            self.panes.remove(parent);
+        }
+    }
+
+    fn replace_child(&mut self, parent: Option<usize>, from: usize, to: usize) {
+        match parent {
+            None => self.root = to,
+            Some(parent) => {
+                if let DockPane::Split { children, .. } = &mut self.panes[parent].pane
+                    && let Some(c) = children.iter_mut().find(|c| **c == from) {
+                    *c = to;
+                }
+            }
+        }
+    }
+
+    // Adds a new group containing only `tab` next to `target`. Returns the new group.
+    fn split_pane(&mut self, target: usize, side: DockSide, tab: T) -> usize {
+        let (axis, after) = side.axis_and_after();
+        let parent = self.panes[target].parent;
+
+        let new_group = self.panes.insert(DockEntry {
+            pane: DockPane::Tabs { tabs: vec![tab], active: 0 },
+            weight: 1.0,
+            parent,
+        });
+
+        let same_axis_parent = parent.filter(|&p| matches!(&self.panes[p].pane, DockPane::Split { axis: parent_axis, .. } if *parent_axis == axis));
+
+        if let Some(parent) = same_axis_parent {
+            // Share the target's space with the new group.
+            let half = self.panes[target].weight / 2.0;
+            self.panes[target].weight = half;
+            self.panes[new_group].weight = half;
+
+            let DockPane::Split { children, .. } = &mut self.panes[parent].pane else { unreachable!() };
+            let pos = children.iter().position(|&c| c == target).unwrap();
+            children.insert(if after { pos + 1 } else { pos }, new_group);
+        } else {
+            // Replace the target with a new split containing the target and the new group.
+            let children = if after { vec![target, new_group] } else { vec![new_group, target] };
+            let split = self.panes.insert(DockEntry {
+                pane: DockPane::Split { axis, children },
+                weight: self.panes[target].weight,
+                parent,
+            });
+            self.replace_child(parent, target, split);
+            self.panes[target].parent = Some(split);
+            self.panes[target].weight = 1.0;
+            self.panes[new_group].parent = Some(split);
+        }
+
+        return new_group;
+    }
+
+    fn split_with_tab(&mut self, tab: T, target: usize, side: DockSide) {
+        let Some(src) = self.find_group(tab) else {
+            return;
+        };
+        // Splitting a group's only tab off itself would leave an empty group behind.
+        if src == target && self.tabs(DockGroup(src)).len() == 1 {
+            return;
+        }
+        self.detach_tab(tab);
+        self.split_pane(target, side, tab);
+        self.remove_group_if_empty(src);
+    }
+
+    // Moves `tab` to `dest`, in the place of the `onto` tab, or at the end.
+    fn move_tab(&mut self, tab: T, dest: usize, onto: Option<T>) {
+        let Some(src) = self.find_group(tab) else {
+            return;
+        };
+        if onto == Some(tab) {
+            return;
+        }
+
+        let position = |layout: &Self, t: T| layout.tabs(DockGroup(dest)).iter().position(|x| *x == t);
+        let moving_right = src == dest && match (onto.and_then(|o| position(self, o)), position(self, tab)) {
+            (Some(onto_pos), Some(tab_pos)) => onto_pos > tab_pos,
+            _ => false,
+        };
+
+        self.detach_tab(tab);
+
+        let DockPane::Tabs { tabs, active } = &mut self.panes[dest].pane else { unreachable!() };
+        let index = match onto.and_then(|o| tabs.iter().position(|t| *t == o)) {
+            Some(pos) if moving_right => pos + 1,
+            Some(pos) => pos,
+            None => tabs.len(),
+        };
+        tabs.insert(index, tab);
+        *active = index;
+
+        self.remove_group_if_empty(src);
+    }
+}
+
+/// Provides the titles and the content of the tabs in a [`DockArea`].
+pub trait DockTabViewer {
+    /// A stable identifier for a tab. It's used for the tabs' node keys, and it's what gets stored in the [`DockLayout`].
+    type Tab: Copy + Eq + std::hash::Hash + 'static;
+
+    fn title(&mut self, tab: Self::Tab) -> &str;
+
+    /// Adds the content of the tab. This is only called for the active tab of each group.
+    fn add_content(&mut self, ui: &mut Ui, tab: Self::Tab);
+
+    /// Returns `true` if the tab should have a close button.
+    fn closable(&mut self, tab: Self::Tab) -> bool {
+        let _ = tab;
+        return true;
+    }
+}
+
+/// What happened in a [`DockArea`] this frame.
+#[derive(Clone, Copy, Debug)]
+pub struct DockAreaResponse<T> {
+    /// A tab was closed with its close button. It's already removed from the layout.
+    pub closed_tab: Option<T>,
+    /// The layout was changed by the user, for example by moving a tab or a splitter. Useful to know when to save it.
+    pub layout_changed: bool,
+}
+
+impl<T> Default for DockAreaResponse<T> {
+    fn default() -> Self {
+        Self { closed_tab: None, layout_changed: false }
+    }
+}
+
+/// An area split into resizable panes, each with a group of tabs.
+///
+/// Tabs can be dragged onto another group's tab bar or center to move them there, or onto the sides of a group to split it. Dragging the borders between groups resizes them.
+///
+/// ```no_run
+/// # use keru::*; let mut ui: Ui = unimplemented!();
+/// struct Panels;
+/// impl DockTabViewer for Panels {
+///     type Tab = &'static str;
+///     fn title(&mut self, tab: &'static str) -> &str { tab }
+///     fn add_content(&mut self, ui: &mut Ui, tab: &'static str) { ui.static_label(tab); }
+/// }
+/// let mut layout = DockLayout::new(["Scene", "Inspector", "Console"]);
+/// ui.add_component(DockArea::new(&mut layout, &mut Panels));
+/// ```
+pub struct DockArea<'a, V: DockTabViewer> {
+    pub layout: &'a mut DockLayout<V::Tab>,
+    pub viewer: &'a mut V,
+    pub key: Option<ComponentKey<Self>>,
+}
+
+impl<'a, V: DockTabViewer> DockArea<'a, V> {
+    pub fn new(layout: &'a mut DockLayout<V::Tab>, viewer: &'a mut V) -> Self {
+        Self { layout, viewer, key: None }
+    }
+
+    pub fn key(mut self, key: ComponentKey<Self>) -> Self {
+        self.key = Some(key);
+        self
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum DockDrop<T> {
+    // Move the tab into the group, in place of the `onto` tab or at the end.
+    Merge { group: usize, onto: Option<T> },
+    Split { group: usize, side: DockSide },
+}
+
+#[node_key] const DOCK_SPLIT: NodeKey;
+#[node_key] const DOCK_SPLITTER: NodeKey;
+#[node_key] const DOCK_GROUP: NodeKey;
+#[node_key] const DOCK_TAB_BAR: NodeKey;
+#[node_key] const DOCK_TAB: NodeKey;
+#[node_key] const DOCK_CLOSE_TAB: NodeKey;
+#[node_key] const DOCK_BODY: NodeKey;
+#[node_key] const DOCK_DROP_CENTER: NodeKey;
+#[node_key] const DOCK_DROP_SIDE: NodeKey;
+#[node_key] const DOCK_DROP_PREVIEW: NodeKey;
+#[node_key] const DOCK_DRAGGED_TAB: NodeKey;
+
+const DOCK_SPLITTER_THICKNESS: f32 = 6.0;
+const DOCK_MIN_PANE_SIZE: f32 = 80.0;
+
+impl<V: DockTabViewer> Component for DockArea<'_, V> {
+    type AddResult = DockAreaResponse<V::Tab>;
+    type ComponentOutput = ();
+    type State = ();
+
+    fn add_to_ui(&mut self, ui: &mut Ui, _state: &mut Self::State) -> Self::AddResult {
+        let mut response = DockAreaResponse::default();
+        let layout = &mut *self.layout;
+
+        // Tabs
+        let all_tabs: Vec<V::Tab> = layout.panes.iter()
+            .flat_map(|(_, entry)| match &entry.pane {
+                DockPane::Tabs { tabs, .. } => tabs.as_slice(),
+                DockPane::Split { .. } => &[],
+            })
+            .copied()
+            .collect();
+
+        for &tab in &all_tabs {
+            if ui.is_click_released(DOCK_CLOSE_TAB.sibling(tab)) {
+                layout.remove_tab(tab);
+                response.closed_tab = Some(tab);
+                response.layout_changed = true;
+                continue;
+            }
+            if ui.is_clicked(DOCK_TAB.sibling(tab)) {
+                layout.set_active_tab(tab);
+            }
+            if let Some(drag) = ui.is_dragged(DOCK_TAB.sibling(tab)) && drag.total_drag_distance.length() > 4.0 {
+                layout.dragged = Some(tab);
+            }
+        }
+
+        // Splitters
+        let splits: Vec<(usize, Axis, Vec<usize>)> = layout.panes.iter()
+            .filter_map(|(i, entry)| match &entry.pane {
+                DockPane::Split { axis, children } => Some((i, *axis, children.clone())),
+                DockPane::Tabs { .. } => None,
+            })
+            .collect();
+
+        for (split, axis, children) in &splits {
+            // Logical pixels, like the drag delta and the minimum pane size.
+            let Some(split_size) = ui.get_node(DOCK_SPLIT.sibling(*split))
+                .map(|n| n.inner_size()[*axis])
+                .filter(|&s| s > 0.0) else { continue };
+
+            let total: f32 = children.iter().map(|&c| layout.panes[c].weight).sum();
+            for pair in children.windows(2) {
+                let (before, after) = (pair[0], pair[1]);
+                let Some(drag) = ui.is_dragged(DOCK_SPLITTER.sibling(before)) else { continue };
+
+                let delta_px = match axis {
+                    Axis::X => drag.absolute_delta.x,
+                    Axis::Y => drag.absolute_delta.y,
+                };
+                let delta = delta_px / split_size * total;
+                let pair_weight = layout.panes[before].weight + layout.panes[after].weight;
+                let min = (DOCK_MIN_PANE_SIZE / split_size * total).min(pair_weight / 2.0);
+                let new_before = (layout.panes[before].weight + delta).clamp(min, pair_weight - min);
+                layout.panes[before].weight = new_before;
+                layout.panes[after].weight = pair_weight - new_before;
+                response.layout_changed = true;
+            }
+        }
+
+        // Dropping tabs
+        let mut hovered_drop = None;
+        if let Some(tab) = layout.dragged {
+            let src_key = DOCK_TAB.sibling(tab);
+            let groups: Vec<usize> = layout.groups().map(|g| g.0).collect();
+
+            let mut targets = Vec::new();
+            for &group in &groups {
+                targets.push((DOCK_DROP_CENTER.sibling(group), DockDrop::Merge { group, onto: None }));
+                targets.push((DOCK_TAB_BAR.sibling(group), DockDrop::Merge { group, onto: None }));
+                for side in DockSide::ALL {
+                    targets.push((DOCK_DROP_SIDE.sibling((group, side)), DockDrop::Split { group, side }));
+                }
+                for &other in layout.tabs(DockGroup(group)) {
+                    if other != tab {
+                        targets.push((DOCK_TAB.sibling(other), DockDrop::Merge { group, onto: Some(other) }));
+                    }
+                }
+            }
+
+            let mut released_drop = None;
+            for (dest_key, drop) in targets {
+                if ui.is_drag_released_onto(src_key, dest_key).is_some() {
+                    released_drop = Some(drop);
+                    break;
+                }
+                if hovered_drop.is_none() && ui.is_drag_hovered_onto(src_key, dest_key).is_some() {
+                    hovered_drop = Some(drop);
+                }
+            }
+
+            match released_drop {
+                Some(DockDrop::Merge { group, onto }) => layout.move_tab(tab, group, onto),
+                Some(DockDrop::Split { group, side }) => layout.split_with_tab(tab, group, side),
+                None => {}
+            }
+            if released_drop.is_some() {
+                response.layout_changed = true;
+            }
+
+            if ui.is_drag_released(src_key) {
+                layout.dragged = None;
+                hovered_drop = None;
+            }
+        }
+
+        let root = self.layout.root;
+        self.add_pane(ui, root, Size::Fill, None, None, hovered_drop);
+
+        // The dragged tab follows the cursor.
+        if let Some(tab) = self.layout.dragged {
+            let cursor = ui.cursor_position();
+            let ghost = BUTTON
+                .text(self.viewer.title(tab))
+                .shape(Shape::Rectangle { rounded_corners: RoundedCorners::TOP, corner_radius: DEFAULT_CORNER_RADIUS })
+                .anchor_symm(Anchor::Center)
+                .position(Pos::Pixels(cursor.x), Pos::Pixels(cursor.y))
+                .absorbs_clicks(false)
+                .z_index(100.0)
+                .key(DOCK_DRAGGED_TAB);
+            ui.jump_to_root().nest(|| {
+                ui.add(ghost);
+            });
+        }
+
+        return response;
+    }
+
+    fn component_key(&self) -> Option<ComponentKey<Self>> {
+        self.key
+    }
+}
+
+impl<V: DockTabViewer> DockArea<'_, V> {
+    // `splitter_axis` is set when the pane has a sibling after it, so that it needs a splitter on its far edge.
+    fn add_pane(&mut self, ui: &mut Ui, i: usize, size_along: Size, parent_axis: Option<Axis>, splitter_axis: Option<Axis>, hovered_drop: Option<DockDrop<V::Tab>>) {
+        let (size_x, size_y) = match parent_axis {
+            Some(Axis::X) => (size_along, Size::Fill),
+            Some(Axis::Y) => (Size::Fill, size_along),
+            None => (Size::Fill, Size::Fill),
+        };
+
+        // The splitter floats over the edge of the pane, so that it doesn't take space away from the panes' fractional sizes.
+        let splitter = splitter_axis.map(|axis| {
+            let splitter = PANEL
+                .padding(0.0)
+                .shape(Shape::Rectangle { rounded_corners: RoundedCorners::ALL, corner_radius: 0.0 })
+                .fill(ui.theme().border)
+                .free_placement(true)
+                .z_index(10.0)
+                .sense_drag(true)
+                .key(DOCK_SPLITTER.sibling(i));
+            match axis {
+                Axis::X => splitter.size(Size::Pixels(DOCK_SPLITTER_THICKNESS), Size::Fill).position(Pos::End, Pos::Start),
+                Axis::Y => splitter.size(Size::Fill, Size::Pixels(DOCK_SPLITTER_THICKNESS)).position(Pos::Start, Pos::End),
+            }
+        });
+
+        match &self.layout.panes[i].pane {
+            DockPane::Split { axis, children } => {
+                let axis = *axis;
+                let children = children.clone();
+                let total: f32 = children.iter().map(|&c| self.layout.panes[c].weight).sum();
+
+                let stack = match axis {
+                    Axis::X => H_STACK,
+                    Axis::Y => V_STACK,
+                }
+                    .size(size_x, size_y)
+                    .stack_spacing(0.0)
+                    .padding(0.0)
+                    .key(DOCK_SPLIT.sibling(i));
+
+                ui.add(stack).nest(|| {
+                    for (n, &child) in children.iter().enumerate() {
+                        let size = Size::Frac(self.layout.panes[child].weight / total);
+                        let child_splitter = if n + 1 < children.len() { Some(axis) } else { None };
+                        self.add_pane(ui, child, size, Some(axis), child_splitter, hovered_drop);
+                    }
+                    if let Some(splitter) = splitter {
+                        ui.add(splitter);
+                    }
+                });
+            }
+            DockPane::Tabs { tabs, active } => {
+                let active_tab = tabs.get(*active).copied();
+                let dragging = self.layout.dragged.is_some();
+
+                let group = V_STACK
+                    .size(size_x, size_y)
+                    .stack_spacing(0.0)
+                    .padding(0.0)
+                    .key(DOCK_GROUP.sibling(i));
+
+                let tab_bar = H_SCROLL_STACK
+                    .size(Size::Fill, Size::FitContent)
+                    .stack_arrange(Arrange::Start)
+                    .stack_spacing(2.0)
+                    .padding(2.0)
+                    .sense_drag_drop_target(true)
+                    .accessibility_role(AccessKitRole::TabList)
+                    .key(DOCK_TAB_BAR.sibling(i));
+
+                let inactive_tab = BUTTON
+                    .stack(Axis::X, Arrange::Start, 6.0)
+                    .shape(Shape::Rectangle { rounded_corners: RoundedCorners::TOP, corner_radius: DEFAULT_CORNER_RADIUS })
+                    .fill(ui.theme().muted_background)
+                    .sense_drag(true)
+                    .sense_drag_drop_target(true)
+                    .accessibility_role(AccessKitRole::Tab);
+                let active_tab_node = inactive_tab.fill(ui.theme().background);
+
+                let close_button = BUTTON
+                    .static_text("✕")
+                    .padding(2.0)
+                    .fill(ui.theme().muted_background);
+
+                let body = PANEL
+                    .size(Size::Fill, Size::Fill)
+                    .shape(Shape::Rectangle { rounded_corners: RoundedCorners::BOTTOM, corner_radius: DEFAULT_CORNER_RADIUS })
+                    .fill(ui.theme().background)
+                    .clip_children(true)
+                    .accessibility_role(AccessKitRole::TabPanel)
+                    .key(DOCK_BODY.sibling(i));
+
+                let drop_zone = CONTAINER
+                    .padding(0.0)
+                    .free_placement(true)
+                    .z_index(20.0)
+                    .sense_drag_drop_target(true);
+
+                let preview = PANEL
+                    .padding(0.0)
+                    .color(Color::KERU_BLUE.with_alpha(0.3))
+                    .free_placement(true)
+                    .absorbs_clicks(false)
+                    .z_index(15.0)
+                    .key(DOCK_DROP_PREVIEW.sibling(i));
+
+                let preview = match hovered_drop {
+                    Some(DockDrop::Merge { group, .. }) if group == i => Some(preview.size(Size::Fill, Size::Fill)),
+                    Some(DockDrop::Split { group, side }) if group == i => Some(match side {
+                        DockSide::Left => preview.size(Size::Frac(0.5), Size::Fill).position(Pos::Start, Pos::Start),
+                        DockSide::Right => preview.size(Size::Frac(0.5), Size::Fill).position(Pos::End, Pos::Start),
+                        DockSide::Top => preview.size(Size::Fill, Size::Frac(0.5)).position(Pos::Start, Pos::Start),
+                        DockSide::Bottom => preview.size(Size::Fill, Size::Frac(0.5)).position(Pos::Start, Pos::End),
+                    }),
+                    _ => None,
+                };
+
+                ui.add(group).nest(|| {
+                    ui.add(tab_bar).nest(|| {
+                        let DockPane::Tabs { tabs, .. } = &self.layout.panes[i].pane else { unreachable!() };
+                        for &tab in tabs {
+                            let is_active = active_tab == Some(tab);
+                            let tab_node = if is_active { active_tab_node } else { inactive_tab }
+                                .accessibility_selected(is_active)
+                                .key(DOCK_TAB.sibling(tab));
+
+                            ui.add(tab_node).nest(|| {
+                                ui.add(TEXT.text(self.viewer.title(tab)).text_selectable(false));
+                                if self.viewer.closable(tab) {
+                                    ui.add(close_button.key(DOCK_CLOSE_TAB.sibling(tab)));
+                                }
+                            });
+                        }
+                    });
+
+                    ui.add(body).nest(|| {
+                        if let Some(tab) = active_tab {
+                            self.viewer.add_content(ui, tab);
+                        }
+
+                        if dragging {
+                            ui.add(drop_zone.size(Size::Frac(0.5), Size::Frac(0.5)).position(Pos::Center, Pos::Center).key(DOCK_DROP_CENTER.sibling(i)));
+                            for side in DockSide::ALL {
+                                let zone = match side {
+                                    DockSide::Left => drop_zone.size(Size::Frac(0.25), Size::Fill).position(Pos::Start, Pos::Start),
+                                    DockSide::Right => drop_zone.size(Size::Frac(0.25), Size::Fill).position(Pos::End, Pos::Start),
+                                    DockSide::Top => drop_zone.size(Size::Fill, Size::Frac(0.25)).position(Pos::Start, Pos::Start),
+                                    DockSide::Bottom => drop_zone.size(Size::Fill, Size::Frac(0.25)).position(Pos::Start, Pos::End),
+                                };
+                                ui.add(zone.key(DOCK_DROP_SIDE.sibling((i, side))));
+                            }
+                        }
+
+                        if let Some(preview) = preview {
+                            ui.add(preview);
+                        }
+                    });
+
+                    if let Some(splitter) = splitter {
+                        ui.add(splitter);
+                    }
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_dock_layout {
+    use super::*;
+
+    fn root_group(layout: &DockLayout<u32>) -> DockGroup {
+        return layout.groups().next().unwrap();
+    }
+
+    fn split_children(layout: &DockLayout<u32>, i: usize) -> (Axis, Vec<usize>) {
+        match &layout.panes[i].pane {
+            DockPane::Split { axis, children } => (*axis, children.clone()),
+            DockPane::Tabs { .. } => panic!("pane {i} isn't a split"),
+        }
+    }
+
+    #[test]
+    fn split_then_remove_collapses() {
+        let mut layout = DockLayout::new([1, 2]);
+        let first = root_group(&layout);
+
+        let second = layout.split(first, DockSide::Right, 3);
+        let (axis, children) = split_children(&layout, layout.root);
+        assert_eq!(axis, Axis::X);
+        assert_eq!(children, vec![first.0, second.0]);
+        assert_eq!(layout.tabs(second), &[3]);
+        assert_eq!(layout.groups().count(), 2);
+
+        // Removing the last tab of a group removes the group, and the split with a single child left is replaced by it.
+        assert!(layout.remove_tab(3));
+        assert_eq!(layout.panes.len(), 1);
+        assert_eq!(layout.root, first.0);
+        assert_eq!(layout.panes[first.0].parent, None);
+        assert_eq!(layout.panes[first.0].weight, 1.0);
+        assert_eq!(layout.tabs(first), &[1, 2]);
+    }
+
+    #[test]
+    fn nested_split_collapses_into_parent() {
+        let mut layout = DockLayout::new([1]);
+        let first = root_group(&layout);
+        let second = layout.split(first, DockSide::Right, 2);
+        let third = layout.split(second, DockSide::Bottom, 3);
+
+        let (_, root_children) = split_children(&layout, layout.root);
+        let nested = root_children[1];
+        let (axis, children) = split_children(&layout, nested);
+        assert_eq!(axis, Axis::Y);
+        assert_eq!(children, vec![second.0, third.0]);
+
+        assert!(layout.remove_tab(3));
+        let (_, root_children) = split_children(&layout, layout.root);
+        assert_eq!(root_children, vec![first.0, second.0]);
+        assert_eq!(layout.panes[second.0].parent, Some(layout.root));
+        assert_eq!(layout.panes.len(), 3);
+    }
+
+    #[test]
+    fn split_along_the_same_axis_shares_the_space() {
+        let mut layout = DockLayout::new([1]);
+        let first = root_group(&layout);
+        let second = layout.split(first, DockSide::Right, 2);
+        let third = layout.split(second, DockSide::Left, 3);
+
+        let (_, children) = split_children(&layout, layout.root);
+        assert_eq!(children, vec![first.0, third.0, second.0]);
+        assert_eq!(layout.panes[second.0].weight + layout.panes[third.0].weight, 1.0);
+    }
+
+    #[test]
+    fn root_group_is_kept_when_empty() {
+        let mut layout = DockLayout::new([1]);
+        let first = root_group(&layout);
+        assert!(layout.remove_tab(1));
+        assert!(! layout.remove_tab(1));
+        assert_eq!(layout.groups().count(), 1);
+        assert_eq!(layout.active_tab(first), None);
+
+        layout.add_tab(first, 2);
+        assert_eq!(layout.active_tab(first), Some(2));
+    }
+
+    #[test]
+    fn moving_the_only_tab_of_a_group_removes_it() {
+        let mut layout = DockLayout::new([1, 2]);
+        let first = root_group(&layout);
+        let second = layout.split(first, DockSide::Bottom, 3);
+
+        layout.add_tab(first, 3);
+        assert_eq!(layout.tabs(first), &[1, 2, 3]);
+        assert_eq!(layout.active_tab(first), Some(3));
+        assert_eq!(layout.find_tab(3), Some(first));
+        assert_eq!(layout.tabs(second), &[] as &[u32]);
+        assert_eq!(layout.panes.len(), 1);
+    }
+
+    #[test]
+    fn removing_a_tab_keeps_the_active_one() {
+        let mut layout = DockLayout::new([1, 2, 3]);
+        let first = root_group(&layout);
+        layout.set_active_tab(3);
+        layout.remove_tab(1);
+        assert_eq!(layout.active_tab(first), Some(3));
+        layout.remove_tab(3);
+        assert_eq!(layout.active_tab(first), Some(2));
+    }
+}
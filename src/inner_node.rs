@@ -5,6 +5,25 @@ use keru_draw::{TextBoxHandle, TextEditHandle};
 use crate::*;
 use crate::node_library::*;
 
+#[derive(Debug, Clone, Copy)]
+pub struct StaggerCounter {
+    frame: u64,
+    count: u32,
+}
+
+impl StaggerCounter {
+    pub const NEW: StaggerCounter = StaggerCounter { frame: 0, count: 0 };
+
+    // Returns how many times this was called before in the same frame.
+    pub fn next(&mut self, current_frame: u64) -> u32 {
+        if self.frame != current_frame {
+            *self = StaggerCounter { frame: current_frame, count: 0 };
+        }
+        self.count += 1;
+        return self.count - 1;
+    }
+}
+
 #[derive(Debug)]
 pub struct InnerNode {
     pub id: Id,
@@ -29,6 +48,9 @@ pub struct InnerNode {
     // todo: should try to get rid of some of these stored rects that are basically just partial results in the layout process.
     pub local_layout_rect: XyRect,
     pub local_animated_rect: XyRect,
+    // The target that the position animation is following, to restart its curve when it changes.
+    pub animation_target: XyRect,
+    pub animation_progress: CurveProgress,
     pub content_bounds: XyRect,
     // could maybe be passed down while traversing instead of stored.
     pub clip_rect: XyRect,
@@ -48,6 +70,20 @@ pub struct InnerNode {
     // With alpha, it's not as common, and it's easy to just multiply the two alphas. So it's simpler and probably fine to use a separate alpha just for the enter-exit animations
     // This way, the cleanup logic is connected just to this new simpler subsystem and not to the whole property animation system. 
     pub fade_alpha: f32,
+    pub fade_progress: CurveProgress,
+
+    // The screen rect before the node moved to a different parent, to animate it from there.
+    pub reparented_from: Option<XyRect>,
+    // While a node animates from its old parent to the new one, it's not clipped by either of them.
+    pub reparent_transition_going: bool,
+
+    // Seconds left before the enter or exit animation starts, set by the parent's stagger_children().
+    pub animation_delay: f32,
+    // The product of the subtree_animation_speed() of this node and all of its ancestors. Updated in the animation traversal.
+    pub subtree_animation_speed: f32,
+    // Counts the children that started an enter or exit animation in the same frame, to stagger them.
+    pub entering_children: StaggerCounter,
+    pub exiting_children: StaggerCounter,
 
     pub relayout_chain_root: Option<NodeI>,
 
@@ -77,6 +113,14 @@ pub struct InnerNode {
     pub params: Node<'static>,
     // Index into ui.sys.params_animation_targets
     pub params_animation_target: Option<NonZeroU32>,
+    // The borrowed text style properties from the last update, kept while a property animation can change the text size or color.
+    pub animated_text_properties: Vec<TextStyleProperty>,
+    // Index into ui.sys.keyframe_animations
+    pub keyframe_animation: Option<NonZeroU32>,
+    // Whether the position, enter, exit or fade animations were still going in the last animation step.
+    pub animating_layout: bool,
+    // The frame in which UiNode::animation_finished() returns true.
+    pub animation_finished_frame: u64,
 
     pub debug_location: &'static Location<'static>,
 
@@ -164,6 +208,13 @@ impl InnerNode {
             exit_animation_still_going: false,
             enter_animation_still_going: false,
             fade_alpha: 1.0,
+            fade_progress: CurveProgress::default(),
+            reparented_from: None,
+            reparent_transition_going: false,
+            animation_delay: 0.0,
+            subtree_animation_speed: 1.0,
+            entering_children: StaggerCounter::NEW,
+            exiting_children: StaggerCounter::NEW,
             id: key.id_with_key_scope(),
             original_key: *key,
             depth: 0,
@@ -171,6 +222,8 @@ impl InnerNode {
             real_rect: Xy::new_symm([0.0, 1.0]),
             local_layout_rect: Xy::new_symm([0.0, 0.0]),
             local_animated_rect: Xy::new_symm([0.0, 0.0]),
+            animation_target: Xy::new_symm([0.0, 0.0]),
+            animation_progress: CurveProgress::default(),
             clip_rect: Xy::new_symm([0.0, 1.0]),
 
             size: Xy::new_symm(0.5),
@@ -210,6 +263,10 @@ impl InnerNode {
             n_twins: 0,
             params: Node::const_default(),
             params_animation_target: None,
+            animated_text_properties: Vec::new(),
+            keyframe_animation: None,
+            animating_layout: false,
+            animation_finished_frame: 0,
 
             debug_location,
             hover_timestamp: f32::MIN,
@@ -296,6 +353,13 @@ pub const NODE_ROOT: InnerNode = InnerNode {
     exit_animation_still_going: false,
     enter_animation_still_going: false,
     fade_alpha: 1.0,
+    fade_progress: CurveProgress::NEW,
+    reparented_from: None,
+    reparent_transition_going: false,
+    animation_delay: 0.0,
+    subtree_animation_speed: 1.0,
+    entering_children: StaggerCounter::NEW,
+    exiting_children: StaggerCounter::NEW,
     id: NODE_ROOT_ID,
     original_key: NodeKey::new(NODE_ROOT_ID, "Root"),
     depth: 0,
@@ -303,6 +367,8 @@ pub const NODE_ROOT: InnerNode = InnerNode {
     real_rect: Xy::new_symm([0.0, 1.0]),
     local_layout_rect: Xy::new_symm([0.0, 1.0]),
     local_animated_rect: Xy::new_symm([0.0, 1.0]),
+    animation_target: Xy::new_symm([0.0, 1.0]),
+    animation_progress: CurveProgress::NEW,
     clip_rect: Xy::new_symm([0.0, 1.0]),
 
     size: Xy::new_symm(1.0),
@@ -343,6 +409,10 @@ pub const NODE_ROOT: InnerNode = InnerNode {
 
     params: NODE_ROOT_PARAMS,
     params_animation_target: None,
+    animated_text_properties: Vec::new(),
+    keyframe_animation: None,
+    animating_layout: false,
+    animation_finished_frame: 0,
     
     debug_location: Location::caller(),
 
@@ -218,6 +218,18 @@ impl Ui {
         self.get_node(key)?.is_hovered()
     }
 
+    /// Returns `true` if any animation of the node corresponding to `key` is still going. See [`UiNode::is_animating()`].
+    pub fn is_animating(&self, key: NodeKey) -> bool {
+        self.get_node_unfiltered(key).is_some_and(|node| node.is_animating())
+    }
+
+    /// Returns `true` if an animation of the node corresponding to `key` finished since the last update, like a [`KeyframeTrack`] that reached its end.
+    ///
+    /// When an animation finishes, the [`Ui`] always runs another update, so this can be used to react to it.
+    pub fn animation_finished(&self, key: NodeKey) -> bool {
+        self.get_node_unfiltered(key).is_some_and(|node| node.animation_finished())
+    }
+
     /// Returns `true` if the node corresponding to `key` is currently holding the keyboard navigation focus.
     pub fn is_focused(&self, key: NodeKey) -> bool {
         // Some non-interactive nodes can be "silently" focused in a way that's just useful for future tab navigation. is_focused shouldn't report that, though, so we also check self.sys.show_focus_indicator
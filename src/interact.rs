@@ -97,6 +97,15 @@ pub(crate) fn filter_hits_by_sense(hits: &[HitNode], sense: Sense) -> SmallVec<I
     result
 }
 
+/// A direction for [`Ui::focus_in_direction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
 bitflags::bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
     pub struct Sense: u16 {
@@ -255,8 +264,17 @@ impl Ui {
     pub(crate) fn begin_frame_resolve_inputs(&mut self) {
         self.sys.mouse_input.begin_new_frame();
         self.sys.key_input.begin_new_frame();
-        let speed = self.sys.global_animation_speed;
-        self.sys.mouse_input.update_animated_scrolls(speed);
+        if self.sys.mouse_input.has_active_animated_scrolls() {
+            // In reduced motion mode, the whole scroll happens in one step.
+            let (speed, dt) = if self.sys.reduced_motion {
+                (f32::INFINITY, 1.0)
+            } else {
+                (self.sys.global_animation_speed, self.sys.scroll_timer.tick(self.sys.animation_clock))
+            };
+            self.sys.mouse_input.update_animated_scrolls(speed, dt);
+        } else {
+            self.sys.scroll_timer.stop();
+        }
     }
 
     pub(crate) fn handle_mouse_press(&mut self, button: MouseButton, window: &Window) -> bool {
@@ -364,6 +382,28 @@ impl Ui {
             }
         }
 
+        if let Key::Named(arrow @ (NamedKey::ArrowUp | NamedKey::ArrowDown | NamedKey::ArrowLeft | NamedKey::ArrowRight)) = &event.logical_key {
+            if event.state.is_pressed() {
+                if let Some(i) = self.sys.focused.and_then(|id| self.sys.nodes.get_by_id(id)) {
+                    // Text edits use the arrow keys to move the cursor, and components like sliders use them to change their value.
+                    let is_text_edit = matches!(self.sys.nodes[i].text_i, Some(TextI::TextEdit(_)));
+                    let uses_arrow_keys = self.sys.nodes[i].params.interact.uses_arrow_keys;
+                    if ! is_text_edit && ! uses_arrow_keys && let Some(container) = self.arrow_navigation_container(i) {
+                        let direction = match arrow {
+                            NamedKey::ArrowUp => FocusDirection::Up,
+                            NamedKey::ArrowDown => FocusDirection::Down,
+                            NamedKey::ArrowLeft => FocusDirection::Left,
+                            _ => FocusDirection::Right,
+                        };
+                        // If there's nothing in that direction, the key isn't consumed.
+                        if self.move_focus_spatially(i, container, direction) {
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+
         if let Key::Named(NamedKey::Escape) = &event.logical_key {
             if event.state.is_pressed() && self.sys.show_focus_indicator {
                 // Hide the focus indicator without losing the focus itself, so a
@@ -426,6 +466,24 @@ impl Ui {
         self.move_keyboard_focus(false);
     }
 
+    /// Move the keyboard focus to the closest focusable node in the given direction, based on the nodes' positions on the screen.
+    ///
+    /// The search is limited to the closest container with [`Node::arrow_key_navigation`] enabled, or to the current focus scope if there isn't one.
+    /// This is the same logic used for the arrow keys, and it can be used to implement gamepad navigation.
+    ///
+    /// Returns `true` if the focus moved.
+    pub fn focus_in_direction(&mut self, direction: FocusDirection) -> bool {
+        let Some(i) = self.sys.focused.and_then(|id| self.sys.nodes.get_by_id(id)) else {
+            self.move_keyboard_focus(true);
+            return self.sys.focused.is_some();
+        };
+        let container = match self.arrow_navigation_container(i) {
+            Some(container) => container,
+            None => self.focus_scope_for(Some(i)),
+        };
+        return self.move_focus_spatially(i, container, direction);
+    }
+
     /// Clear the keyboard focus.
     pub fn unfocus(&mut self) {
         self.sys.focused = None;
@@ -435,54 +493,203 @@ impl Ui {
         self.sys.changes.focus_changed = true;
     }
 
-    /// Move the keyboard focus to the next (or previous) interactable node in
-    /// depth-first order, wrapping around at the ends.
+    /// Move the keyboard focus to the next (or previous) node in the tab order, wrapping around at the ends.
+    ///
+    /// The tab order is the depth-first order of the focusable nodes, except that nodes with a positive [`Interact::tab_index`] come first.
+    /// The navigation stays inside the innermost [`Interact::focus_scope`] containing the focused node, or inside the active [`Interact::focus_trap`].
     ///
-    /// If nothing is focused yet, focuses the first interactable node.
+    /// If nothing is focused yet, focuses the first node in the tab order.
     pub(crate) fn move_keyboard_focus(&mut self, forward: bool) {
         // Using the keyboard always reveals the focus indicator, even if the
         // focus doesn't end up moving (e.g. a single interactable node).
         self.sys.show_focus_indicator = true;
         self.sys.changes.should_rebuild_render_data = true;
 
-        let Some(first) = self.first_node() else { return; };
-        let Some(last) = self.last_node() else { return; };
+        let focused = self.sys.focused.and_then(|id| self.sys.nodes.get_by_id(id));
+        let scope = self.focus_scope_for(focused);
 
-        // The node we start scanning from. When nothing is focused (or the
-        // focused node no longer exists), the first step should land on the
-        // very first (or last) node of the tree.
-        let start = match self.sys.focused.and_then(|id| self.sys.nodes.get_by_id(id)) {
-            Some(i) => i,
-            None => {
-                let candidate = if forward { first } else { last };
-                if self.is_interactable_for_focus(candidate) {
-                    self.set_focus_node(candidate, true);
-                    self.sys.scroll_node_into_view(candidate, SCROLL_INTO_VIEW_PADDING_PIXELS, true);
-                    return;
+        let order = self.tab_order(scope);
+        let (Some(&first), Some(&last)) = (order.first(), order.last()) else { return; };
+
+        let target = match focused.filter(|&i| i != scope && self.is_in_subtree(i, scope)) {
+            // When nothing is focused (or the focused node no longer exists, or it's outside of the scope), the first step should land on the very first (or last) node.
+            None => if forward { first } else { last },
+            Some(focused) => match order.iter().position(|&i| i == focused) {
+                Some(pos) => {
+                    let len = order.len();
+                    if forward { order[(pos + 1) % len] } else { order[(pos + len - 1) % len] }
+                }
+                // The focused node isn't a tab stop (e.g. a non-interactable node focused by a click): continue from the closest tab stop in tree order.
+                None => {
+                    let (Some(tree_first), Some(tree_last)) = (self.first_node(scope), self.last_node(scope)) else { return; };
+                    let mut cursor = focused;
+                    loop {
+                        cursor = if forward {
+                            self.next_node(cursor, scope).unwrap_or(tree_first)
+                        } else {
+                            self.prev_node(cursor, scope).unwrap_or(tree_last)
+                        };
+                        if order.contains(&cursor) {
+                            break cursor;
+                        }
+                        // Walked the whole scope without finding a tab stop.
+                        if cursor == focused {
+                            break if forward { first } else { last };
+                        }
+                    }
                 }
-                candidate
             }
         };
 
-        let mut cursor = start;
-        loop {
-            cursor = if forward {
-                self.next_node(cursor).unwrap_or(first)
+        self.set_focus_node(target, true);
+        self.sys.scroll_node_into_view(target, SCROLL_INTO_VIEW_PADDING_PIXELS, true);
+    }
+
+    /// The subtree that Tab navigation should cycle within, given the currently focused node.
+    fn focus_scope_for(&self, focused: Option<NodeI>) -> NodeI {
+        let outer = self.active_focus_trap().unwrap_or(ROOT_I);
+        let Some(focused) = focused else { return outer; };
+        if ! self.is_in_subtree(focused, outer) {
+            return outer;
+        }
+
+        let mut cursor = focused;
+        while cursor != outer && cursor != ROOT_I {
+            if self.sys.nodes[cursor].params.interact.focus_scope {
+                return cursor;
+            }
+            cursor = self.sys.nodes[cursor].parent;
+        }
+        return outer;
+    }
+
+    /// All the tab stops inside `scope` (not including `scope` itself), in tab order.
+    fn tab_order(&self, scope: NodeI) -> Vec<NodeI> {
+        let mut stops = Vec::new();
+        self.collect_tab_stops(scope, &mut stops);
+        // Stable sort, so nodes with the same tab index stay in tree order.
+        stops.sort_by_key(|&(_, tab_index)| if tab_index > 0 { (0, tab_index) } else { (1, 0) });
+        return stops.into_iter().map(|(i, _)| i).collect();
+    }
+
+    fn collect_tab_stops(&self, parent: NodeI, stops: &mut Vec<(NodeI, i16)>) {
+        let mut child = self.sys.nodes[parent].first_child;
+        while let Some(c) = child {
+            let interact = &self.sys.nodes[c].params.interact;
+            let tab_index = interact.tab_index;
+
+            if interact.skip_tab_navigation {
+                // Skip the node together with all its children.
+            } else if interact.focus_scope {
+                // A nested scope is a single stop in the outer order: either the scope node itself, or the first stop inside it.
+                if tab_index >= 0 {
+                    if self.is_interactable_for_focus(c) {
+                        stops.push((c, tab_index));
+                    } else if let Some(&inner_first) = self.tab_order(c).first() {
+                        stops.push((inner_first, tab_index));
+                    }
+                }
             } else {
-                self.prev_node(cursor).unwrap_or(last)
+                if tab_index >= 0 && self.is_interactable_for_focus(c) {
+                    stops.push((c, tab_index));
+                }
+                self.collect_tab_stops(c, stops);
+            }
+
+            child = self.sys.nodes[c].next_sibling;
+        }
+    }
+
+    /// The closest ancestor of `i` with [`Interact::arrow_navigation`] enabled.
+    fn arrow_navigation_container(&self, i: NodeI) -> Option<NodeI> {
+        let mut cursor = self.sys.nodes[i].parent;
+        while cursor != ROOT_I {
+            if self.sys.nodes[cursor].params.interact.arrow_navigation {
+                return Some(cursor);
+            }
+            cursor = self.sys.nodes[cursor].parent;
+        }
+        return None;
+    }
+
+    fn move_focus_spatially(&mut self, from: NodeI, container: NodeI, direction: FocusDirection) -> bool {
+        // Work in pixels, so that the distances aren't skewed by the window's aspect ratio.
+        let size = self.sys.size;
+        let center_px = |i: NodeI| {
+            let c = self.sys.nodes[i].real_rect.center();
+            Vec2::new(c.x * size.x, c.y * size.y)
+        };
+        let origin = center_px(from);
+
+        let mut best: Option<(NodeI, f32)> = None;
+        for candidate in self.spatial_focus_candidates(container) {
+            if candidate == from {
+                continue;
+            }
+            let delta = center_px(candidate) - origin;
+            // Distance along the direction, and distance across it.
+            let (along, across) = match direction {
+                FocusDirection::Up => (-delta.y, delta.x.abs()),
+                FocusDirection::Down => (delta.y, delta.x.abs()),
+                FocusDirection::Left => (-delta.x, delta.y.abs()),
+                FocusDirection::Right => (delta.x, delta.y.abs()),
             };
+            if along <= 0.5 {
+                continue;
+            }
+            // Prefer nodes that are well aligned with the direction over ones that are slightly closer but off to the side.
+            let score = along + 2.0 * across;
+            if best.is_none_or(|(_, best_score)| score < best_score) {
+                best = Some((candidate, score));
+            }
+        }
+
+        let Some((target, _)) = best else { return false; };
+        self.set_focus_node(target, true);
+        self.sys.scroll_node_into_view(target, SCROLL_INTO_VIEW_PADDING_PIXELS, true);
+        return true;
+    }
+
+    /// All the focusable nodes inside `container`.
+    ///
+    /// Unlike the tab order, this includes nodes inside nested focus scopes, nodes skipped by Tab navigation and nodes with a negative tab index.
+    fn spatial_focus_candidates(&self, container: NodeI) -> Vec<NodeI> {
+        let mut candidates = Vec::new();
+        let mut cursor = self.first_node(container);
+        while let Some(i) = cursor {
+            if self.is_interactable_for_focus(i) {
+                candidates.push(i);
+            }
+            cursor = self.next_node(i, container);
+        }
+        return candidates;
+    }
 
-            if self.is_interactable_for_focus(cursor) {
-                self.set_focus_node(cursor, true);
-                self.sys.scroll_node_into_view(cursor, SCROLL_INTO_VIEW_PADDING_PIXELS, true);
-                return;
+    /// The last node in tree order that has [`Interact::focus_trap`] set, if any.
+    fn active_focus_trap(&self) -> Option<NodeI> {
+        let mut result = None;
+        let mut cursor = self.first_node(ROOT_I);
+        while let Some(i) = cursor {
+            if self.sys.nodes[i].params.interact.focus_trap {
+                result = Some(i);
             }
+            cursor = self.next_node(i, ROOT_I);
+        }
+        return result;
+    }
 
-            // Walked the whole tree without finding anything interactable.
-            if cursor == start {
-                return;
+    fn is_in_subtree(&self, i: NodeI, root: NodeI) -> bool {
+        if root == ROOT_I {
+            return true;
+        }
+        let mut cursor = i;
+        while cursor != ROOT_I {
+            if cursor == root {
+                return true;
             }
+            cursor = self.sys.nodes[cursor].parent;
         }
+        return false;
     }
 
     pub(crate) fn set_focus_node(&mut self, i: NodeI, show_indicator: bool) {
@@ -508,20 +715,21 @@ impl Ui {
         return self.sys.nodes[i].params.interact.focusable;
     }
 
-    fn first_node(&self) -> Option<NodeI> {
-        self.sys.nodes[ROOT_I].first_child
+    /// The first node in depth-first order inside `scope`, not counting `scope` itself.
+    fn first_node(&self, scope: NodeI) -> Option<NodeI> {
+        self.sys.nodes[scope].first_child
     }
 
-    /// The last node of the tree in depth-first order (deepest last descendant).
-    fn last_node(&self) -> Option<NodeI> {
-        let mut cursor = self.sys.nodes[ROOT_I].last_child?;
+    /// The last node in depth-first order inside `scope` (deepest last descendant).
+    fn last_node(&self, scope: NodeI) -> Option<NodeI> {
+        let mut cursor = self.sys.nodes[scope].last_child?;
         while let Some(child) = self.sys.nodes[cursor].last_child {
             cursor = child;
         }
         Some(cursor)
     }
 
-    fn next_node(&self, i: NodeI) -> Option<NodeI> {
+    fn next_node(&self, i: NodeI, scope: NodeI) -> Option<NodeI> {
         if let Some(child) = self.sys.nodes[i].first_child {
             return Some(child);
         }
@@ -531,14 +739,14 @@ impl Ui {
                 return Some(sibling);
             }
             let parent = self.sys.nodes[cursor].parent;
-            if parent == ROOT_I {
+            if parent == scope || parent == ROOT_I {
                 return None;
             }
             cursor = parent;
         }
     }
 
-    fn prev_node(&self, i: NodeI) -> Option<NodeI> {
+    fn prev_node(&self, i: NodeI, scope: NodeI) -> Option<NodeI> {
         if let Some(sibling) = self.sys.nodes[i].prev_sibling {
             // Deepest last descendant of the previous sibling.
             let mut cursor = sibling;
@@ -548,7 +756,7 @@ impl Ui {
             return Some(cursor);
         }
         let parent = self.sys.nodes[i].parent;
-        if parent == ROOT_I {
+        if parent == scope || parent == ROOT_I {
             return None;
         }
         Some(parent)
@@ -962,6 +1170,9 @@ impl System {
     pub(crate) fn check_hovered(&self, id: Id) -> bool {
         #[cfg(debug_assertions)] {
             if let Some(i) = self.nodes.get_by_id(id) {
+                if self.nodes[i].params.interact.disabled {
+                    return false;
+                }
                 let senses = self.nodes[i].params.interact.senses;
                 if !senses.intersects(Sense::HOVER | Sense::HOVER_ENTER_OR_EXIT) {
                     eprintln!(
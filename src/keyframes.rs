@@ -0,0 +1,380 @@
+use crate::*;
+use crate::animation_curve::{cubic_bezier, ease_in_out_cubic};
+use std::num::NonZeroU32;
+
+/// How a [`Keyframe`] is reached from the previous one.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    /// Start slow, then accelerate.
+    EaseIn,
+    /// Start fast, then decelerate.
+    EaseOut,
+    /// Accelerate, then decelerate.
+    EaseInOut,
+    /// A CSS-style cubic bezier timing function, with control points `(x1, y1)` and `(x2, y2)`.
+    CubicBezier { x1: f32, y1: f32, x2: f32, y2: f32 },
+    /// Keep the previous value, then jump to the new one when the keyframe is reached.
+    Hold,
+}
+
+impl Easing {
+    fn apply(self, x: f32) -> f32 {
+        match self {
+            Easing::Linear => x,
+            Easing::EaseIn => x * x * x,
+            Easing::EaseOut => 1.0 - (1.0 - x).powi(3),
+            Easing::EaseInOut => ease_in_out_cubic(x),
+            Easing::CubicBezier { x1, y1, x2, y2 } => cubic_bezier(x1, y1, x2, y2, x),
+            Easing::Hold => if x >= 1.0 { 1.0 } else { 0.0 },
+        }
+    }
+}
+
+/// A value at a point in time, in seconds from the start of a [`KeyframeTrack`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Keyframe {
+    pub time: f32,
+    pub value: f32,
+    /// How the value gets here from the previous keyframe.
+    pub easing: Easing,
+}
+
+impl Keyframe {
+    pub const fn new(time: f32, value: f32) -> Self {
+        return Self { time, value, easing: Easing::Linear };
+    }
+
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        return self;
+    }
+}
+
+/// The node property animated by a [`KeyframeTrack`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum KeyframeProperty {
+    Alpha,
+    /// The zoom set by [`Node::scale()`].
+    Scale,
+    /// The horizontal translation set by [`Node::translate()`].
+    OffsetX,
+    /// The vertical translation set by [`Node::translate()`].
+    OffsetY,
+    Blur,
+    /// The width of the node's stroke. It has no effect on nodes without a stroke.
+    StrokeWidth,
+    /// The corner radius of a rectangle. It has no effect on other shapes.
+    CornerRadius,
+}
+
+impl KeyframeProperty {
+    /// Whether this property moves or scales the node. These tracks don't play in [reduced motion mode](Ui::set_reduced_motion).
+    pub(crate) fn is_motion(self) -> bool {
+        return matches!(self, KeyframeProperty::Scale | KeyframeProperty::OffsetX | KeyframeProperty::OffsetY);
+    }
+}
+
+/// What a [`KeyframeTrack`] does after its last keyframe.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Repeat {
+    /// Play once, then stay on the last value.
+    #[default]
+    Once,
+    /// Play this many times, then stay on the last value.
+    Times(u32),
+    /// Start over from the first keyframe, forever.
+    Loop,
+    /// Play forwards, then backwards, forever.
+    PingPong,
+}
+
+/// A timeline of [`Keyframe`]s for a single property.
+///
+/// Set them with [`Node::keyframes()`]. The timeline starts when the node is added, and starts over whenever the tracks change.
+///
+/// ```no_run
+/// # use keru::*;
+/// const PULSE: &[Keyframe] = &[
+///     Keyframe::new(0.0, 1.0),
+///     Keyframe::new(0.4, 1.2).easing(Easing::EaseOut),
+///     Keyframe::new(0.8, 1.0).easing(Easing::EaseIn),
+/// ];
+/// const TRACKS: &[KeyframeTrack] = &[KeyframeTrack::new(KeyframeProperty::Scale, PULSE).repeat(Repeat::Loop)];
+/// # fn declare(ui: &mut Ui) {
+/// ui.add(node_library::BUTTON.static_text("Pulsing").keyframes(TRACKS));
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KeyframeTrack<'a> {
+    pub property: KeyframeProperty,
+    /// The keyframes, sorted by time.
+    pub keyframes: &'a [Keyframe],
+    pub repeat: Repeat,
+}
+
+impl<'a> KeyframeTrack<'a> {
+    pub const fn new(property: KeyframeProperty, keyframes: &'a [Keyframe]) -> Self {
+        return Self { property, keyframes, repeat: Repeat::Once };
+    }
+
+    pub const fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        return self;
+    }
+}
+
+// Tracks are borrowed from the caller, so they're copied to keep playing in frames where the update code doesn't run.
+#[derive(Debug, Clone)]
+struct OwnedTrack {
+    property: KeyframeProperty,
+    keyframes: Vec<Keyframe>,
+    repeat: Repeat,
+}
+
+impl OwnedTrack {
+    // Returns the value at time `t`, and whether the track is finished.
+    fn sample(&self, t: f32) -> (f32, bool) {
+        let (Some(first), Some(last)) = (self.keyframes.first(), self.keyframes.last()) else {
+            return (0.0, true);
+        };
+        let duration = last.time;
+        // With disabled animations, every track skips to its end, even looping ones.
+        if duration <= 0.0 || t.is_infinite() {
+            return (last.value, true);
+        }
+
+        let (local_t, finished) = match self.repeat {
+            Repeat::Once => (t.min(duration), t >= duration),
+            Repeat::Times(n) => {
+                let total = duration * n as f32;
+                if t >= total { (duration, true) } else { (t % duration, false) }
+            },
+            Repeat::Loop => (t % duration, false),
+            Repeat::PingPong => {
+                let t = t % (2.0 * duration);
+                (if t > duration { 2.0 * duration - t } else { t }, false)
+            },
+        };
+
+        if local_t <= first.time {
+            return (first.value, finished);
+        }
+        let next = self.keyframes.iter().position(|k| k.time >= local_t).unwrap_or(self.keyframes.len() - 1);
+        let (a, b) = (self.keyframes[next - 1], self.keyframes[next]);
+        let span = b.time - a.time;
+        let x = if span <= 0.0 { 1.0 } else { (local_t - a.time) / span };
+        let value = a.value + (b.value - a.value) * b.easing.apply(x);
+        return (value, finished);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct KeyframeAnimation {
+    i: NodeI,
+    id: Id,
+    tracks: Vec<OwnedTrack>,
+    elapsed: f32,
+    finished: bool,
+}
+
+impl KeyframeAnimation {
+    fn same_tracks(&self, tracks: &[KeyframeTrack]) -> bool {
+        return self.tracks.len() == tracks.len() && self.tracks.iter().zip(tracks).all(|(a, b)| {
+            a.property == b.property && a.repeat == b.repeat && a.keyframes == b.keyframes
+        });
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        return ! self.finished;
+    }
+}
+
+impl Ui {
+    // Called from set_params with the node's new keyframe tracks.
+    pub(crate) fn set_keyframes(&mut self, i: NodeI, tracks: &[KeyframeTrack]) {
+        let current = self.sys.nodes[i].keyframe_animation.map(|anim_i| anim_i.get() as usize - 1);
+
+        if tracks.is_empty() {
+            if let Some(anim_i) = current {
+                self.sys.keyframe_animations.remove(anim_i);
+                self.sys.nodes[i].keyframe_animation = None;
+            }
+            return;
+        }
+
+        if let Some(anim_i) = current && self.sys.keyframe_animations[anim_i].same_tracks(tracks) {
+            return;
+        }
+
+        let new_anim = KeyframeAnimation {
+            i,
+            id: self.sys.nodes[i].id,
+            tracks: tracks.iter().map(|t| OwnedTrack { property: t.property, keyframes: t.keyframes.to_vec(), repeat: t.repeat }).collect(),
+            elapsed: 0.0,
+            finished: false,
+        };
+        match current {
+            // The tracks changed: start over.
+            Some(anim_i) => self.sys.keyframe_animations[anim_i] = new_anim,
+            None => {
+                let anim_i = self.sys.keyframe_animations.insert(new_anim);
+                self.sys.nodes[i].keyframe_animation = Some(NonZeroU32::new(anim_i as u32 + 1).unwrap());
+            },
+        }
+        self.sys.changes.should_rebuild_render_data = true;
+    }
+
+    // Advance all the keyframe animations and write their values into the node params.
+    //
+    // This runs before the animation traversal, so that animated transforms are picked up by it.
+    // Finished animations keep writing their final values, since an update overwrites them with the node's own params.
+    pub(crate) fn update_keyframe_animations(&mut self) {
+        for slab_i in 0..self.sys.keyframe_animations.capacity() {
+            let Some(anim) = self.sys.keyframe_animations.get(slab_i) else {
+                continue;
+            };
+            let (i, id) = (anim.i, anim.id);
+            // The node got replaced by another one.
+            if self.sys.nodes[i].id != id {
+                self.sys.keyframe_animations.remove(slab_i);
+                continue;
+            }
+            // Don't keep the render loop going for nodes that aren't shown.
+            if self.sys.nodes[i].currently_hidden {
+                continue;
+            }
+
+            let step_time = self.sys.animation_step_time(i);
+            let reduced_motion = self.sys.reduced_motion;
+            let anim = &mut self.sys.keyframe_animations[slab_i];
+            let was_running = anim.is_running();
+            if was_running {
+                anim.elapsed += step_time;
+            }
+
+            let mut all_finished = true;
+            let params = &mut self.sys.nodes[i].params;
+            for track in &anim.tracks {
+                // An infinite time skips straight to the end.
+                let t = if reduced_motion && track.property.is_motion() { f32::INFINITY } else { anim.elapsed };
+                let (value, finished) = track.sample(t);
+                all_finished &= finished;
+                apply_keyframe_value(params, track.property, value);
+            }
+            anim.finished = all_finished;
+
+            if all_finished {
+                if was_running {
+                    self.sys.mark_animation_finished(i);
+                }
+            } else {
+                self.sys.changes.unfinished_animations = true;
+            }
+        }
+    }
+}
+
+fn apply_keyframe_value(params: &mut Node<'static>, property: KeyframeProperty, value: f32) {
+    match property {
+        KeyframeProperty::Alpha => params.alpha = value,
+        KeyframeProperty::Scale => params.transform.scale = value,
+        KeyframeProperty::OffsetX => params.transform.offset.x = value,
+        KeyframeProperty::OffsetY => params.transform.offset.y = value,
+        KeyframeProperty::Blur => params.blur = Some(value),
+        KeyframeProperty::StrokeWidth => {
+            if let Some(stroke) = &mut params.stroke {
+                stroke.width = value;
+            }
+        },
+        KeyframeProperty::CornerRadius => {
+            if let Shape::Rectangle { corner_radius, .. } = &mut params.shape {
+                *corner_radius = value;
+            }
+        },
+    }
+}
+
+impl System {
+    // Make `UiNode::animation_finished()` true in the next update, and make sure that there is one.
+    pub(crate) fn mark_animation_finished(&mut self, i: NodeI) {
+        self.nodes[i].animation_finished_frame = self.current_frame + 1;
+        self.update_frames_needed = 2;
+    }
+}
+
+#[cfg(test)]
+mod test_keyframe_tracks {
+    use super::*;
+
+    fn track(keyframes: &[Keyframe], repeat: Repeat) -> OwnedTrack {
+        return OwnedTrack { property: KeyframeProperty::Scale, keyframes: keyframes.to_vec(), repeat };
+    }
+
+    fn close(a: f32, b: f32) -> bool {
+        return (a - b).abs() < 1e-5;
+    }
+
+    const RAMP: &[Keyframe] = &[Keyframe::new(0.0, 0.0), Keyframe::new(1.0, 10.0)];
+
+    #[test]
+    fn once() {
+        let track = track(RAMP, Repeat::Once);
+        assert_eq!(track.sample(0.0), (0.0, false));
+        let (value, finished) = track.sample(0.25);
+        assert!(close(value, 2.5) && ! finished);
+        assert_eq!(track.sample(1.0), (10.0, true));
+        assert_eq!(track.sample(3.0), (10.0, true));
+    }
+
+    #[test]
+    fn times() {
+        let track = track(RAMP, Repeat::Times(2));
+        let (value, finished) = track.sample(1.5);
+        assert!(close(value, 5.0) && ! finished);
+        assert_eq!(track.sample(2.0), (10.0, true));
+        assert_eq!(track.sample(5.0), (10.0, true));
+    }
+
+    #[test]
+    fn loop_and_ping_pong() {
+        let looping = track(RAMP, Repeat::Loop);
+        let (value, finished) = looping.sample(2.25);
+        assert!(close(value, 2.5) && ! finished);
+
+        let ping_pong = track(RAMP, Repeat::PingPong);
+        let (value, finished) = ping_pong.sample(1.25);
+        assert!(close(value, 7.5) && ! finished);
+        let (value, finished) = ping_pong.sample(2.25);
+        assert!(close(value, 2.5) && ! finished);
+        assert!(! ping_pong.sample(100.0).1);
+    }
+
+    #[test]
+    fn hold() {
+        let keyframes = [Keyframe::new(0.0, 1.0), Keyframe::new(0.5, 2.0).easing(Easing::Hold), Keyframe::new(1.0, 3.0)];
+        let track = track(&keyframes, Repeat::Once);
+        assert_eq!(track.sample(0.49).0, 1.0);
+        assert_eq!(track.sample(0.5).0, 2.0);
+        // The keyframe after a held one is reached normally.
+        assert!(close(track.sample(0.75).0, 2.5));
+    }
+
+    #[test]
+    fn single_keyframe() {
+        let at_start = track(&[Keyframe::new(0.0, 4.0)], Repeat::Loop);
+        assert_eq!(at_start.sample(0.0), (4.0, true));
+
+        let later = track(&[Keyframe::new(0.5, 4.0)], Repeat::Loop);
+        assert_eq!(later.sample(0.2).0, 4.0);
+        assert_eq!(later.sample(0.7).0, 4.0);
+    }
+
+    #[test]
+    fn infinite_time_skips_to_the_end() {
+        for repeat in [Repeat::Once, Repeat::Times(3), Repeat::Loop, Repeat::PingPong] {
+            assert_eq!(track(RAMP, repeat).sample(f32::INFINITY), (10.0, true));
+        }
+    }
+}
@@ -152,10 +152,12 @@ impl Ui {
         let partial_relayouts = ! self.sys.changes.partial_relayouts.is_empty();
         let full_relayout = self.sys.changes.full_relayout;
         let text_changed = self.sys.changes.text_changed;
-        let nothing_to_do = !partial_relayouts && !full_relayout && !text_changed;
+        let pending_text_relayout = self.sys.changes.pending_text_relayout;
+        let nothing_to_do = !partial_relayouts && !full_relayout && !text_changed && !pending_text_relayout;
         if nothing_to_do {
             return;
         }
+        self.sys.changes.pending_text_relayout = false;
 
         // if anything happened at all, we'll need to rerender.
         self.sys.changes.need_gpu_rect_update = true;
@@ -873,7 +875,8 @@ impl Ui {
 
         self.sys.nodes[i].local_animated_rect = self.sys.nodes[i].local_layout_rect;
 
-        match self.sys.nodes[i].params.animation.enter {
+        let enter_animation = self.sys.enter_animation(i);
+        match enter_animation {
             EnterAnimation::None => {}
             EnterAnimation::Slide { edge, direction: _ } => {
                 use SlideEdge::*;
@@ -922,9 +925,17 @@ impl Ui {
             }
             EnterAnimation::FadeIn => {
                 self.sys.nodes[i].fade_alpha = 0.0;
+                self.sys.nodes[i].fade_progress.restart();
                 // We don't need to set enter_animation_still_going, as that's only needed for when enter/exit animations interact with the regular position interpolation ones
             }
         }
+
+        if enter_animation != EnterAnimation::None {
+            let parent = self.sys.nodes[i].parent;
+            let current_frame = self.sys.current_frame;
+            let index = self.sys.nodes[parent].entering_children.next(current_frame);
+            self.sys.nodes[i].animation_delay = index as f32 * self.sys.nodes[parent].params.animation.stagger;
+        }
     }
 
     pub(crate) fn init_exit_animations(&mut self, i: NodeI) {
@@ -934,13 +945,20 @@ impl Ui {
         }
         // Set exiting even if we don't have an exiting animation, because the node might need to stick around for a parent's exit animation.
         self.sys.nodes[i].exiting = true;
+        self.sys.nodes[i].fade_progress.restart();
 
-        if self.sys.nodes[i].params.animation.exit == ExitAnimation::None {
+        let exit_animation = self.sys.exit_animation(i);
+        if exit_animation == ExitAnimation::None {
             return;
         }
 
         self.sys.nodes[i].exit_animation_still_going = true;
 
+        let parent = self.sys.nodes[i].parent;
+        let current_frame = self.sys.current_frame;
+        let index = self.sys.nodes[parent].exiting_children.next(current_frame);
+        self.sys.nodes[i].animation_delay = index as f32 * self.sys.nodes[parent].params.animation.stagger;
+
         // set the whole branch to exiting.
         with_arena(|a| {
             let mut stack = BumpVec::with_capacity_in(20, a);
@@ -957,7 +975,7 @@ impl Ui {
             }
         });
 
-        match self.sys.nodes[i].params.animation.exit {
+        match exit_animation {
             ExitAnimation::None => {}
             ExitAnimation::Slide { edge, direction: _ } => {
                 use SlideEdge::*;
@@ -1020,7 +1038,7 @@ impl Ui {
     pub(crate) fn set_clip_rect(&mut self, i: NodeI) {
         // Start from the parent's clip rect.
         // If nobody wants to clip children, this will always be [0.0, 1.0], passed down from root to everything else. 
-        let parent_clip_rect = if i == ROOT_I {
+        let parent_clip_rect = if i == ROOT_I || self.sys.nodes[i].reparent_transition_going {
             Xy::new_symm([0.0, 1.0])
         } else {
             let parent = self.sys.nodes[i].parent;
@@ -1041,6 +1059,10 @@ impl Ui {
     pub(crate) fn rebuild_render_data(&mut self) {
         self.sys.renderer.begin_frame();
 
+        self.sys.animation_dt = self.sys.animation_timer.tick(self.sys.animation_clock);
+
+        self.update_keyframe_animations();
+
         // This is another separate traversal:
         // - separate from layout because of no-relayout animations
         // - separate from push_render_data so that prepare_text() can run after it knows whether any textbox changed, but before push_render_data.
@@ -1048,6 +1070,10 @@ impl Ui {
 
         self.update_property_animations();
 
+        if ! self.sys.changes.unfinished_animations {
+            self.sys.animation_timer.stop();
+        }
+
         with_timer("prepare_text", Some(std::time::Duration::from_micros(500)), || {
             self.sys.renderer.prepare_text();
         });
@@ -1064,6 +1090,7 @@ impl Ui {
             node: NodeI,
             parent_scroll: Xy<f32>,
             parent_expected_final_rect: XyRect,
+            parent_subtree_speed: f32,
         }
 
         with_arena(|arena| {
@@ -1072,10 +1099,14 @@ impl Ui {
                 node: ROOT_I,
                 parent_scroll: Xy::new(0.0, 0.0),
                 parent_expected_final_rect: XyRect::new_symm([0.0, 0.0]),
+                parent_subtree_speed: 1.0,
             });
 
             while let Some(entry) = traversal_queue.pop() {
                 let i = entry.node;
+                let subtree_speed = entry.parent_subtree_speed * self.sys.nodes[i].params.animation.subtree_speed;
+                self.sys.nodes[i].subtree_animation_speed = subtree_speed;
+
                 self.update_scroll_animation(i);
                 let expected_final_rect = self.resolve_animations_and_scrolling(i, entry.parent_scroll, entry.parent_expected_final_rect);
 
@@ -1090,6 +1121,7 @@ impl Ui {
                         node: child,
                         parent_scroll: child_scroll,
                         parent_expected_final_rect: expected_final_rect,
+                        parent_subtree_speed: subtree_speed,
                     });
                 });
             }
@@ -1231,8 +1263,14 @@ impl Ui {
     }
 
     pub(crate) fn resolve_animations_and_scrolling(&mut self, i: NodeI, parent_scroll: Xy<f32>, parent_expected_final_rect: XyRect) -> XyRect {
+        self.start_reparent_transition(i, parent_scroll);
+
         let still_moving = self.resolve_animation(i);
 
+        if ! still_moving {
+            self.sys.nodes[i].reparent_transition_going = false;
+        }
+
         // add the parent offset
         let parent = self.sys.nodes[i].parent;
 
@@ -1246,12 +1284,12 @@ impl Ui {
         let parent_exiting = self.sys.nodes[parent].exiting;
         if parent_enter_going || parent_exit_going || parent_exiting {
 
-            let parent_enter_anim = &self.sys.nodes[parent].params.animation.enter;
-            let parent_exit_anim = &self.sys.nodes[parent].params.animation.exit;
+            let parent_enter_anim = self.sys.enter_animation(parent);
+            let parent_exit_anim = self.sys.exit_animation(parent);
 
             if parent_enter_going {
                 let layout_tl = parent_expected_final_rect.top_left();
-                if let EnterAnimation::Grow { axis, origin } = *parent_enter_anim {
+                if let EnterAnimation::Grow { axis, origin } = parent_enter_anim {
                     match origin {
                         Pos::End | Pos::Center => match axis {
                             Axis::X => parent_offset.x = layout_tl.x,
@@ -1263,7 +1301,7 @@ impl Ui {
             }
             if parent_exiting {
                 let parent_size = self.sys.nodes[parent].layout_rect.size();
-                if let ExitAnimation::Shrink { axis, origin } = *parent_exit_anim {
+                if let ExitAnimation::Shrink { axis, origin } = parent_exit_anim {
                     match origin {
                         Pos::End => match axis {
                             Axis::X => parent_offset.x = self.sys.nodes[parent].real_rect.x[1] - parent_size.x,
@@ -1302,6 +1340,11 @@ impl Ui {
 
         let parent = self.sys.nodes[i].parent;
         let parent_exiting = self.sys.nodes[parent].exit_animation_still_going;
+        if self.sys.nodes[i].animating_layout && !still_moving {
+            self.sys.mark_animation_finished(i);
+        }
+        self.sys.nodes[i].animating_layout = still_moving;
+
         if !still_moving && !parent_exiting {
             if self.sys.nodes[i].exiting {
                 self.sys.nodes[i].exit_animation_still_going = false;
@@ -1320,7 +1363,35 @@ impl Ui {
         expected_final_rect
     }
 
+    // A node that moved to a different parent starts from its old screen rect, converted to the new parent's space.
+    // The parent was already resolved in this traversal, so its real_rect is up to date.
+    fn start_reparent_transition(&mut self, i: NodeI, parent_scroll: Xy<f32>) {
+        let Some(old_rect) = self.sys.nodes[i].reparented_from.take() else {
+            return;
+        };
+        if ! self.sys.nodes[i].params.animation.state_transition.animate_position {
+            return;
+        }
+
+        let parent = self.sys.nodes[i].parent;
+        let parent_offset = self.sys.nodes[parent].real_rect.top_left();
+        let scroll = if self.sys.nodes[i].params.ignore_parent_scroll {
+            Xy::new(0.0, 0.0)
+        } else {
+            parent_scroll
+        };
+        self.sys.nodes[i].local_animated_rect = old_rect - parent_offset - scroll;
+        self.sys.nodes[i].animation_progress.restart();
+        self.sys.nodes[i].reparent_transition_going = true;
+    }
+
     pub(crate) fn resolve_animation(&mut self, i: NodeI) -> bool {
+        // A staggered enter or exit animation waits in its starting state.
+        if self.sys.nodes[i].animation_delay > 0.0 {
+            self.sys.nodes[i].animation_delay -= self.sys.animation_step_time(i);
+            return true;
+        }
+
         // do animations in local space
         let target = self.sys.nodes[i].local_layout_rect;
 
@@ -1330,53 +1401,80 @@ impl Ui {
         let animate_position = self.sys.nodes[i].params.animation.state_transition.animate_position;
         let enter_anim = self.sys.nodes[i].enter_animation_still_going;
         let exit_anim = self.sys.nodes[i].exit_animation_still_going;
-        let skip_animations = (!animate_position && !enter_anim && !exit_anim) || (self.sys.disable_animations_on_resize && self.sys.changes.resize);
+        let skip_animations = (!animate_position && !enter_anim && !exit_anim)
+            || (self.sys.disable_animations_on_resize && self.sys.changes.resize)
+            || self.sys.reduced_motion;
 
         if ! skip_animations {
             l = self.sys.nodes[i].local_animated_rect;
 
-            let speed = self.sys.global_animation_speed * self.sys.nodes[i].params.animation.speed;
-
-            let dt = 1.0 / 60.0; // todo use real frame time
-
-            let rate = 5.0 * speed * dt;
+            let speed = self.sys.animation_speed(i);
+            let curve = self.sys.nodes[i].params.animation.curve;
 
-            let const_speed_pixels = 3.0 * speed;
-            let diff = target - l;
+            let step_time = self.sys.animation_step_time(i);
 
-            for i in 0..2 {
-                // convert normalized diff into pixel space
-                let dx_px = diff[X][i] * self.sys.size.x;
-                let dy_px = diff[Y][i] * self.sys.size.y;
-
-                let dist_px = (dx_px * dx_px + dy_px * dy_px).sqrt();
+            // The curve starts over when the target moves.
+            if self.sys.nodes[i].animation_target != target {
+                self.sys.nodes[i].animation_target = target;
+                self.sys.nodes[i].animation_progress.restart();
+            }
 
-                if dist_px < const_speed_pixels {
-                    l[X][i] = target[X][i];
-                    l[Y][i] = target[Y][i];
-                } else {
-                    still_moving = true;
-                    // normalized direction in pixel space
-                    let dir_x = dx_px / dist_px;
-                    let dir_y = dy_px / dist_px;
+            if l != target {
+                let step = self.sys.nodes[i].animation_progress.advance(curve, step_time, 5.0);
+                match step {
+                    CurveStep::Exponential { rate, dt } => {
+                        let const_speed_pixels = 3.0 * speed;
+                        // About one pixel per frame at 60 fps.
+                        let min_step_px = 60.0 * dt;
+                        let diff = target - l;
+
+                        for i in 0..2 {
+                            // convert normalized diff into pixel space
+                            let dx_px = diff[X][i] * self.sys.size.x;
+                            let dy_px = diff[Y][i] * self.sys.size.y;
+
+                            let dist_px = (dx_px * dx_px + dy_px * dy_px).sqrt();
+
+                            if dist_px < const_speed_pixels {
+                                l[X][i] = target[X][i];
+                                l[Y][i] = target[Y][i];
+                            } else {
+                                still_moving = true;
+                                // normalized direction in pixel space
+                                let dir_x = dx_px / dist_px;
+                                let dir_y = dy_px / dist_px;
 
-                    // same math concept as before but applied along straight-line distance
-                    let step_px = (dist_px * rate).ceil();
+                                // same math concept as before but applied along straight-line distance
+                                let step_px = (dist_px * rate).max(min_step_px).min(dist_px);
 
-                    l[X][i] += (step_px * dir_x) / self.sys.size.x;
-                    l[Y][i] += (step_px * dir_y) / self.sys.size.y;
+                                l[X][i] += (step_px * dir_x) / self.sys.size.x;
+                                l[Y][i] += (step_px * dir_y) / self.sys.size.y;
+                            }
+                        }
+                    }
+                    CurveStep::Fraction(_) => {
+                        still_moving = true;
+                        for axis in [X, Y] {
+                            for side in 0..2 {
+                                l[axis][side] = step.apply(l[axis][side], target[axis][side], 0.0, 0.0).0;
+                            }
+                        }
+                    }
+                    CurveStep::Done => {
+                        l = target;
+                    }
                 }
             }
         }
 
         self.sys.nodes[i].local_animated_rect = l;
 
-        let fade_exiting_animation = self.sys.nodes[i].params.animation.exit == ExitAnimation::FadeOut;
+        let fade_exiting_animation = self.sys.exit_animation(i) == ExitAnimation::FadeOut;
         let fade_target = if self.sys.nodes[i].exiting && fade_exiting_animation { 0.0 } else { 1.0 };
         if self.sys.nodes[i].fade_alpha != fade_target {
-            let speed = self.sys.global_animation_speed * self.sys.nodes[i].params.animation.speed;
-            let rate = (5.0 * speed / 60.0).clamp(0.0, 1.0);
-            let (new_fade, fade_done) = step_f32(self.sys.nodes[i].fade_alpha, fade_target, rate);
+            let curve = self.sys.nodes[i].params.animation.curve;
+            let step = self.sys.nodes[i].fade_progress.advance(curve, self.sys.animation_step_time(i), 5.0);
+            let (new_fade, fade_done) = step_f32(self.sys.nodes[i].fade_alpha, fade_target, step);
             self.sys.nodes[i].fade_alpha = new_fade;
             if ! fade_done {
                 still_moving = true;
@@ -1471,8 +1569,16 @@ impl Ui {
             }
             moved = true;
 
-            let speed = self.sys.global_animation_speed * self.sys.nodes[i].params.animation.speed;
-            let rate = 5.0 * speed * (1.0 / 60.0);
+            // In reduced motion mode, scrolls jump to their target.
+            let rate = if self.sys.reduced_motion {
+                1.0
+            } else {
+                (5.0 * self.sys.animation_step_time(i)).clamp(0.0, 1.0)
+            };
+            if rate >= 1.0 {
+                self.sys.nodes[i].scroll[axis] = target;
+                continue;
+            }
 
             let diff_px = diff * self.sys.size[axis];
             let dist_px = diff_px.abs();
@@ -1505,6 +1611,35 @@ impl Ui {
 }
 
 impl System {
+    // The animation speed of node `i`, including the global speed and the subtree speeds of all its ancestors.
+    // Keyframe animations run before the animation traversal, so for them the subtree speed comes from when the node's params were set.
+    pub(crate) fn animation_speed(&self, i: NodeI) -> f32 {
+        return self.global_animation_speed * self.nodes[i].params.animation.speed * self.nodes[i].subtree_animation_speed;
+    }
+
+    // How many seconds the animations of node `i` advance in this frame. Infinite if they're disabled, even when no time passed.
+    pub(crate) fn animation_step_time(&self, i: NodeI) -> f32 {
+        let speed = self.animation_speed(i);
+        if speed.is_infinite() {
+            return f32::INFINITY;
+        }
+        return speed * self.animation_dt;
+    }
+
+    // In reduced motion mode, slides and grows are replaced by a fade.
+    pub(crate) fn enter_animation(&self, i: NodeI) -> EnterAnimation {
+        return match self.nodes[i].params.animation.enter {
+            EnterAnimation::Slide { .. } | EnterAnimation::Grow { .. } if self.reduced_motion => EnterAnimation::FadeIn,
+            enter => enter,
+        };
+    }
+
+    pub(crate) fn exit_animation(&self, i: NodeI) -> ExitAnimation {
+        return match self.nodes[i].params.animation.exit {
+            ExitAnimation::Slide { .. } | ExitAnimation::Shrink { .. } if self.reduced_motion => ExitAnimation::FadeOut,
+            exit => exit,
+        };
+    }
 
     /// Adjust the scroll offsets of all scrollable ancestors of `i` so that node
     /// `i` ends up inside their visible rects. Used by keyboard focus navigation
@@ -84,6 +84,15 @@ pub use node_key::*;
 mod node;
 pub use node::*;
 
+mod animation_curve;
+pub use animation_curve::*;
+
+mod animation_clock;
+pub use animation_clock::*;
+
+mod keyframes;
+pub use keyframes::*;
+
 mod key_scope;
 pub use key_scope::*;
 
@@ -110,6 +119,15 @@ pub use thread_local_arena::*;
 mod component_library;
 pub use component_library::*;
 
+mod dock_area;
+pub use dock_area::*;
+
+mod color_picker;
+pub use color_picker::*;
+
+mod toasts;
+pub use toasts::*;
+
 mod interact;
 pub use crate::interact::*;
 
@@ -159,3 +177,4 @@ pub use keru_draw::keru_text;
 
 pub use accesskit::Role as AccessKitRole;
 pub use accesskit::Action as AccessKitAction;
+pub use accesskit::Live as AccessKitLive;
@@ -289,8 +289,9 @@ impl MouseInput {
         }
     }
 
-    pub fn update_animated_scrolls(&mut self, animation_speed: f32) -> bool {
-        let rate = (5.0 * animation_speed * (1.0 / 60.0)).clamp(0.0, 1.0);
+    /// Advance the animated scrolls by `dt` seconds.
+    pub fn update_animated_scrolls(&mut self, animation_speed: f32, dt: f32) -> bool {
+        let rate = (5.0 * animation_speed * dt).clamp(0.0, 1.0);
         let mut any_active = false;
         self.animated_scrolls.retain_mut(|a| {
             let mut step = a.remaining * rate;
@@ -81,6 +81,7 @@ pub struct Node<'a> {
     /// Clip all children of the node to the node's bounding box (not its shape).
     pub clip_children: Xy<bool>,
     pub animation: Animation,
+    pub keyframes: &'a [KeyframeTrack<'a>],
     pub transform: Transform,
     pub custom_render: bool,
     /// Draw order priority among siblings. Higher value = drawn on top. Default is 0.0.
@@ -172,13 +173,18 @@ pub enum ExitAnimation {
 pub struct StateTransition {
     // For now, just position-based transitions (placeholder)
     pub animate_position: bool,
-    // Animate cosmetic property changes (alpha, fill color). Off by default: properties snap.
+    // Animate cosmetic property changes (alpha, colors, shape params, stroke, shadows, blur, text size and color). Off by default: properties snap.
     pub animate_properties: bool,
 }
 
 #[derive(Debug, Copy, Clone)]
 pub struct Animation {
     pub speed: f32,
+    pub curve: AnimationCurve,
+    // Seconds between the enter or exit animations of children that start together.
+    pub stagger: f32,
+    // Multiplies the animation speed of this node and all of its descendants. Infinity disables their animations.
+    pub subtree_speed: f32,
     pub enter: EnterAnimation,
     pub exit: ExitAnimation,
     pub state_transition: StateTransition,
@@ -186,6 +192,9 @@ pub struct Animation {
 
 pub const NO_ANIMATION: Animation = Animation {
     speed: 1.0,
+    curve: AnimationCurve::Exponential,
+    stagger: 0.0,
+    subtree_speed: 1.0,
     enter: EnterAnimation::None,
     exit: ExitAnimation::None,
     state_transition: StateTransition {
@@ -291,6 +300,12 @@ pub struct Accessibility {
     /// Numeric value and bounds for range widgets, announced and adjustable by
     /// screen readers. `None` for non-range nodes.
     pub numeric_value: Option<NumericValue>,
+    /// Expanded state for collapsible nodes such as tree items. `None` for
+    /// nodes that can't be expanded.
+    pub expanded: Option<bool>,
+    /// How screen readers announce changes to the node's content. `None` for
+    /// nodes that aren't live regions.
+    pub live: Option<AccessKitLive>,
     /// Extra actions this node advertises, in addition to the ones keru derives
     /// automatically from the node's role and interactions.
     pub actions: AccessibilityActions,
@@ -299,7 +314,7 @@ pub struct Accessibility {
 impl Accessibility {
     /// Default accessibility properties for the given role.
     pub const fn new(role: AccessKitRole) -> Accessibility {
-        Accessibility { role, selected: false, numeric_value: None, actions: AccessibilityActions::NONE }
+        Accessibility { role, selected: false, numeric_value: None, expanded: None, live: None, actions: AccessibilityActions::NONE }
     }
 }
 
@@ -510,6 +525,20 @@ pub struct Interact {
     pub show_focus_indicator: bool,
     /// Whether the node can receive the keyboard-navigation focus.
     pub focusable: bool,
+    /// Whether Tab navigation is trapped inside the node's subtree while the node is in the tree.
+    pub focus_trap: bool,
+    /// Whether Tab navigation cycles inside the node's subtree when the focus is inside it.
+    pub focus_scope: bool,
+    /// Whether the node and all its children are skipped by Tab navigation.
+    pub skip_tab_navigation: bool,
+    /// Position in the Tab order. Positive values are visited first, in ascending order, then nodes with `0` in tree order. Negative values are never reached with Tab.
+    pub tab_index: i16,
+    /// Whether the arrow keys move the focus spatially between the focusable nodes in the node's subtree.
+    pub arrow_navigation: bool,
+    /// Whether the node handles the arrow keys itself while it's focused, so that they don't move the focus.
+    pub uses_arrow_keys: bool,
+    /// Whether the node is disabled. See [`Node::disabled`].
+    pub disabled: bool,
 }
 
 /// The node's layout, size and position.
@@ -973,6 +1002,9 @@ impl<'a> Node<'a> {
         self.second_shadow.hash(&mut h);
         self.stroke.hash(&mut h);
         self.animation.speed.to_bits().hash(&mut h);
+        self.animation.curve.hash(&mut h);
+        self.animation.stagger.to_bits().hash(&mut h);
+        self.animation.subtree_speed.to_bits().hash(&mut h);
         std::mem::discriminant(&self.animation.enter).hash(&mut h);
         match self.animation.enter {
             EnterAnimation::None => {},
@@ -1163,6 +1195,14 @@ impl<'a> Node<'a> {
         return self;
     }
 
+    /// Animate the node's properties with keyframe timelines. See [`KeyframeTrack`].
+    ///
+    /// The values from the keyframes replace the ones set on the node. Use [`Ui::is_animating()`] and [`Ui::animation_finished()`] to follow the timeline's progress.
+    pub const fn keyframes(mut self, tracks: &'a [KeyframeTrack<'a>]) -> Self {
+        self.keyframes = tracks;
+        return self;
+    }
+
     /// Enable or disable the default click/hover animation.
     pub fn click_animation(mut self, value: bool) -> Self {
         self.interact.click_animation = value;
@@ -1183,6 +1223,61 @@ impl<'a> Node<'a> {
         return self;
     }
 
+    /// Trap the Tab keyboard navigation inside this node's subtree, as long as the node is in the tree.
+    ///
+    /// Used for modal dialogs. If multiple nodes trap the focus, the last one in tree order wins.
+    pub fn trap_focus(mut self, value: bool) -> Self {
+        self.interact.focus_trap = value;
+        return self;
+    }
+
+    /// Make this node a focus scope: when the focus is inside its subtree, Tab cycles between the nodes in the subtree.
+    ///
+    /// From the outside, the whole scope counts as a single Tab stop.
+    pub fn focus_scope(mut self, value: bool) -> Self {
+        self.interact.focus_scope = value;
+        return self;
+    }
+
+    /// Skip this node and all its children when navigating with Tab. The nodes can still be focused by clicking them.
+    pub fn skip_tab_navigation(mut self, value: bool) -> Self {
+        self.interact.skip_tab_navigation = value;
+        return self;
+    }
+
+    /// Override the node's position in the Tab order, like the HTML `tabindex`.
+    ///
+    /// Nodes with a positive index are visited first, in ascending order. Then come the nodes with the default index `0`, in tree order. Nodes with a negative index are never reached with Tab.
+    pub fn tab_index(mut self, index: i16) -> Self {
+        self.interact.tab_index = index;
+        return self;
+    }
+
+    /// Let the arrow keys move the focus to the closest focusable node in that direction, among the nodes in this node's subtree.
+    ///
+    /// Focused text edits and nodes with [`Node::uses_arrow_keys`] keep receiving the arrow keys as usual.
+    pub fn arrow_key_navigation(mut self, value: bool) -> Self {
+        self.interact.arrow_navigation = value;
+        return self;
+    }
+
+    /// Mark the node as handling the arrow keys itself while it's focused, like a slider.
+    ///
+    /// Inside an [`arrow_key_navigation`](Node::arrow_key_navigation) container, the arrow keys go to the node instead of moving the focus.
+    pub fn uses_arrow_keys(mut self, value: bool) -> Self {
+        self.interact.uses_arrow_keys = value;
+        return self;
+    }
+
+    /// Disable the node and all its children.
+    ///
+    /// A disabled node doesn't sense any input, can't be focused, doesn't play the click animation, and is drawn with the theme's `disabled` and `text_disabled` colors.
+    /// It still absorbs mouse events, so clicks don't go through it.
+    pub const fn disabled(mut self, value: bool) -> Self {
+        self.interact.disabled = value;
+        return self;
+    }
+
 
     /// Mark a node as "constant".
     /// 
@@ -1728,6 +1823,41 @@ impl<'a> Node<'a> {
         return self;
     }
 
+    /// Set the [`AnimationCurve`] used by all of this node's animations: position transitions, enter and exit animations, fades and property transitions.
+    pub const fn animation_curve(mut self, curve: AnimationCurve) -> Self {
+        self.animation.curve = curve;
+        return self;
+    }
+
+    /// Stagger the enter and exit animations of this node's children.
+    ///
+    /// When several children enter or exit in the same frame, for example when a filtered list changes, each one starts its animation `delay` seconds after the previous one.
+    ///
+    /// This only delays the children's own [`EnterAnimation`] and [`ExitAnimation`].
+    pub const fn stagger_children(mut self, delay: f32) -> Self {
+        self.animation.stagger = delay;
+        return self;
+    }
+
+    /// Multiply the animation speed of this node and all of its descendants.
+    ///
+    /// This stacks with the descendants' own [`Node::animation_speed()`], with the speed of other ancestors, and with [`Ui::set_global_animation_speed()`].
+    ///
+    /// Speeds below `0.01`, including zero and negative values, are clamped to `0.01`: a speed of zero would freeze the animations, and keep the render loop running forever.
+    pub const fn subtree_animation_speed(mut self, speed: f32) -> Self {
+        const MIN_SUBTREE_SPEED: f32 = 0.01;
+        self.animation.subtree_speed = speed.max(MIN_SUBTREE_SPEED);
+        return self;
+    }
+
+    /// Make all animations of this node and its descendants complete instantly.
+    ///
+    /// Enter and exit animations, position and property transitions, scroll animations and keyframe timelines all jump to their final state.
+    pub const fn disable_subtree_animations(mut self) -> Self {
+        self.animation.subtree_speed = f32::INFINITY;
+        return self;
+    }
+
     // Enter animation methods
     /// Set the enter slide animation.
     pub const fn enter_slide(mut self, edge: SlideEdge, direction: SlideDirection) -> Self {
@@ -1859,14 +1989,18 @@ impl<'a> Node<'a> {
     }
 
     /// Animate position changes when this node moves.
+    ///
+    /// This includes moving to a different parent: if a node with the same key is added under a different parent, it animates from its old place on the screen to the new one, instead of snapping there. While it moves, it isn't clipped by either parent.
     pub const fn animate_position(mut self, value: bool) -> Self {
         self.animation.state_transition.animate_position = value;
         return self;
     }
 
-    /// Animate cosmetic property changes (alpha, fill color) when this node's params change.
+    /// Animate property changes when this node's params change: alpha, fill color, shape parameters like the corner radius, stroke, shadows, blur, text size and text color.
     ///
     /// Off by default, in which case the properties snap to their new value.
+    ///
+    /// Properties that can't be interpolated, like a different shape type or a stroke that is added or removed, still snap.
     pub const fn animate_properties(mut self, value: bool) -> Self {
         self.animation.state_transition.animate_properties = value;
         return self;
@@ -1991,11 +2125,31 @@ impl<'a> Node<'a> {
     /// Meaningful for range widgets with a role such as
     /// [`AccessKitRole::Slider`] or [`AccessKitRole::SpinButton`]: the value is
     /// announced and the widget can be adjusted (see [`Ui::accesskit_action`]).
+    ///
+    /// Infinite `min` or `max` values are treated as unbounded and aren't reported.
     pub const fn accessibility_numeric_value(mut self, value: f64, min: f64, max: f64) -> Node<'a> {
         self.accessibility.numeric_value = Some(NumericValue { value, min, max });
         return self;
     }
 
+    /// Expose whether this [`Node`] is expanded or collapsed to screen readers.
+    ///
+    /// Meaningful for collapsible nodes such as [`AccessKitRole::TreeItem`].
+    /// Also advertises the [`AccessibilityActions::EXPAND`] and
+    /// [`AccessibilityActions::COLLAPSE`] actions.
+    pub const fn accessibility_expanded(mut self, expanded: bool) -> Node<'a> {
+        self.accessibility.expanded = Some(expanded);
+        return self;
+    }
+
+    /// Make this [`Node`] a live region, so that screen readers announce it when it appears or when its content changes.
+    ///
+    /// Use [`AccessKitLive::Polite`] for most notifications, and [`AccessKitLive::Assertive`] for urgent ones like errors.
+    pub const fn accessibility_live(mut self, live: AccessKitLive) -> Node<'a> {
+        self.accessibility.live = Some(live);
+        return self;
+    }
+
     /// Advertise extra [`AccessibilityActions`] to screen readers for this [`Node`].
     ///
     /// The actions are then requestable by an assistive technology and
@@ -2070,6 +2224,32 @@ fn apply_markdown<'a>(text: &str, arena: &'a bumpalo::Bump) -> (BumpString<'a>,
     return (string, style_ranges);
 }
 
+fn text_style_overrides<'b>(
+    flags: TextStyleFlags,
+    text_properties: &[TextStyleProperty],
+    text_size: Option<f32>,
+    text_color: Option<Color>,
+    arena: &'b bumpalo::Bump,
+) -> Option<BumpVec<'b, TextStyleProperty>> {
+    let has_any_style = !flags.is_empty() || text_size.is_some() || text_color.is_some() || !text_properties.is_empty();
+    if ! has_any_style {
+        return None;
+    }
+    let flag_count = flags.bits().count_ones() as usize;
+    let mut properties = BumpVec::with_capacity_in(text_properties.len() + flag_count + 2, arena);
+    if flags.contains(TextStyleFlags::BOLD) { properties.push(BOLD); }
+    if flags.contains(TextStyleFlags::ITALIC) { properties.push(ITALIC); }
+    if flags.contains(TextStyleFlags::MONOSPACE) { properties.push(MONOSPACE); }
+    properties.extend_from_slice(text_properties);
+    if let Some(font_size) = text_size {
+        properties.push(TextStyleProperty::FontSize(font_size));
+    }
+    if let Some(color) = text_color {
+        properties.push(TextStyleProperty::Brush(keru_draw::ColorBrush(color.to_u8_array())));
+    }
+    return Some(properties);
+}
+
 impl Ui {
     pub(crate) fn set_params_text(&mut self, i: NodeI, node: &Node) {
         with_arena(|arena| {
@@ -2158,26 +2338,18 @@ impl Ui {
 
             }
 
+            // While a property animation is in flight, the params hold the animated text size and color rather than the new ones.
+            let params = &self.sys.nodes[i].params;
+            let (text_size, text_color) = (params.text_size, params.text_color);
+            if self.sys.nodes[i].params_animation_target.is_some() {
+                let saved = &mut self.sys.nodes[i].animated_text_properties;
+                saved.clear();
+                saved.extend_from_slice(node.text_properties);
+            }
+
             if let Some(text_i) = &self.sys.nodes[i].text_i {
 
-                let mut properties_opt: Option<BumpVec<StyleProperty<_>>> = None;
-                let flags = node.text_style_flags;
-                let has_any_style = !flags.is_empty() || node.text_size.is_some() || node.text_color.is_some() || !node.text_properties.is_empty();
-                if has_any_style {
-                    let flag_count = flags.bits().count_ones() as usize;
-                    let mut properties = BumpVec::with_capacity_in(node.text_properties.len() + flag_count + 2, arena);
-                    if flags.contains(TextStyleFlags::BOLD) { properties.push(BOLD); }
-                    if flags.contains(TextStyleFlags::ITALIC) { properties.push(ITALIC); }
-                    if flags.contains(TextStyleFlags::MONOSPACE) { properties.push(MONOSPACE); }
-                    properties.extend_from_slice(node.text_properties);
-                    if let Some(font_size) = node.text_size {
-                        properties.push(TextStyleProperty::FontSize(font_size));
-                    }
-                    if let Some(color) = node.text_color {
-                        properties.push(TextStyleProperty::Brush(keru_draw::ColorBrush(color.to_u8_array())));
-                    }
-                    properties_opt = Some(properties);
-                }
+                let properties_opt = text_style_overrides(node.text_style_flags, node.text_properties, text_size, text_color, arena);
 
                 match text_i {
                     TextI::TextEdit(handle) => {
@@ -2226,6 +2398,33 @@ impl Ui {
     }
 
 
+    /// If the node or its parent is disabled, turn off its interactions and use the disabled colors.
+    pub(crate) fn apply_disabled_state<'a>(&self, i: NodeI, mut node: Node<'a>) -> Node<'a> {
+        let parent = self.sys.nodes[i].parent;
+        if ! node.interact.disabled && ! self.sys.nodes[parent].params.interact.disabled {
+            return node;
+        }
+
+        let was_interactive = node.interact.senses != Sense::NONE || node.interact.focusable || node.text_options.editable;
+
+        node.interact.disabled = true;
+        node.interact.senses = Sense::NONE;
+        node.interact.focusable = false;
+        node.interact.click_animation = false;
+        node.text_options.edit_disabled = true;
+
+        let theme = &self.sys.theme;
+        // Only recolor the nodes that would normally be interactive, not every panel in a disabled container.
+        if was_interactive && node.visible {
+            node.color = theme.disabled;
+        }
+        if let ColorFill2::Color(text_color) = theme.text_disabled {
+            node.text_color = Some(text_color);
+        }
+
+        return node;
+    }
+
     pub(crate) fn set_params(&mut self, i: NodeI, node: &Node) {
         #[cfg(not(debug_assertions))]
         if reactive::is_in_skipped_reactive_block() {
@@ -2246,6 +2445,8 @@ impl Ui {
             };
         }
 
+        self.set_keyframes(i, node.keyframes);
+
         let new_cosmetic_hash = node.cosmetic_hash();
         let new_layout_hash = node.layout_hash();
         
@@ -2284,8 +2485,9 @@ impl Ui {
         let mut new_params = node.remove_borrowed_data_and_copy();
 
         let animate_enabled = node.animation.state_transition.animate_properties;
+        // text_size is part of the layout hash.
         let animate = animate_enabled
-            && cosmetic_changed
+            && (cosmetic_changed || layout_changed)
             && self.sys.nodes[i].frame_added != self.sys.current_frame;
 
         // If we didn't support animation-only frames where the builtin animations progress even without rerunning the update code,
@@ -2305,6 +2507,10 @@ impl Ui {
 
         self.sys.nodes[i].params = new_params;
 
+        // Keyframe animations run before the animation traversal updates this, so they need it to be right already.
+        let parent = self.sys.nodes[i].parent;
+        self.sys.nodes[i].subtree_animation_speed = self.sys.nodes[parent].subtree_animation_speed * self.sys.nodes[i].params.animation.subtree_speed;
+
         self.sys.nodes[i].last_cosmetic_hash = new_cosmetic_hash;
         self.sys.nodes[i].last_layout_hash = new_layout_hash;
 
@@ -2330,6 +2536,7 @@ impl Ui {
                 // Update the target values in case the changed before the animation is done
                 let anim_i = anim_i.get() as usize - 1;
                 self.sys.params_animation_targets[anim_i].target = *new_params;
+                self.sys.params_animation_targets[anim_i].progress.restart();
             },
             None => {
                 // Start a new animation
@@ -2337,6 +2544,7 @@ impl Ui {
                     target: *new_params,
                     i,
                     id: self.sys.nodes[i].id,
+                    progress: CurveProgress::default(),
                 };
                 let new_anim_i = self.sys.params_animation_targets.insert(new_anim);
                 let new_anim_i = NonZeroU32::new(new_anim_i as u32 + 1).unwrap();
@@ -2358,13 +2566,31 @@ impl Ui {
         if same_shape_variant(current.shape, new_params.shape) {
             new_params.shape = current.shape;
         }
+        // Optional params can only be interpolated when they're set both before and after. Otherwise they snap.
+        if current.blur.is_some() && new_params.blur.is_some() {
+            new_params.blur = current.blur;
+        }
+        if current.shadow.is_some() && new_params.shadow.is_some() {
+            new_params.shadow = current.shadow;
+        }
+        if current.second_shadow.is_some() && new_params.second_shadow.is_some() {
+            new_params.second_shadow = current.second_shadow;
+        }
+        if let (Some(current_stroke), Some(new_stroke)) = (current.stroke, new_params.stroke)
+            && same_fill_variant(current_stroke.color, new_stroke.color) {
+            new_params.stroke = current.stroke;
+        }
+        if current.text_size.is_some() && new_params.text_size.is_some() {
+            new_params.text_size = current.text_size;
+        }
+        if current.text_color.is_some() && new_params.text_color.is_some() {
+            new_params.text_color = current.text_color;
+        }
     }
 
     pub(crate) fn update_property_animations(&mut self) {
-        let dt = 1.0 / 60.0; // todo use real frame time
-
         for slab_i in 0..self.sys.params_animation_targets.capacity() {
-            let Some(&ParamsAnimation { target, i, id }) = self.sys.params_animation_targets.get(slab_i) else {
+            let Some(&ParamsAnimation { target, i, id, .. }) = self.sys.params_animation_targets.get(slab_i) else {
                 continue;
             };
             // The node got replaced by another one.
@@ -2373,16 +2599,36 @@ impl Ui {
                 continue;
             }
 
-            let speed = 0.5 * self.sys.global_animation_speed * self.sys.nodes[i].params.animation.speed;
-            let rate = (5.0 * speed * dt).clamp(0.0, 1.0);
+            let curve = self.sys.nodes[i].params.animation.curve;
+            // The exponential curve is half as fast here as for positions.
+            let step = self.sys.params_animation_targets[slab_i].progress.advance(curve, self.sys.animation_step_time(i), 2.5);
 
             let mut done = true;
             let mut changed = false;
 
             let params = &mut self.sys.nodes[i].params;
-            done &= animate_alpha(&mut params.alpha, target.alpha, rate, &mut changed);
-            done &= animate_color_fill(&mut params.color, target.color, rate, &mut changed);
-            done &= animate_shape(&mut params.shape, target.shape, rate, &mut changed);
+            done &= animate_property(&mut params.alpha, target.alpha, step, &mut changed, step_f32);
+            done &= animate_property(&mut params.color, target.color, step, &mut changed, step_color_fill);
+            done &= animate_property(&mut params.shape, target.shape, step, &mut changed, step_shape);
+            done &= animate_property(&mut params.blur, target.blur, step, &mut changed, |a, b, s| step_option(a, b, s, step_f32));
+            done &= animate_property(&mut params.shadow, target.shadow, step, &mut changed, |a, b, s| step_option(a, b, s, step_shadow));
+            done &= animate_property(&mut params.second_shadow, target.second_shadow, step, &mut changed, |a, b, s| step_option(a, b, s, step_shadow));
+            done &= animate_property(&mut params.stroke, target.stroke, step, &mut changed, |a, b, s| step_option(a, b, s, step_stroke));
+
+            let mut text_size_changed = false;
+            let mut text_color_changed = false;
+            done &= animate_property(&mut params.text_size, target.text_size, step, &mut text_size_changed, |a, b, s| step_option(a, b, s, step_f32));
+            done &= animate_property(&mut params.text_color, target.text_color, step, &mut text_color_changed, |a, b, s| step_option(a, b, s, step_color));
+
+            if text_size_changed || text_color_changed {
+                self.update_animated_text_style(i);
+                changed = true;
+            }
+            if text_size_changed {
+                // The new size has to go through layout, which already ran for this frame.
+                self.sys.changes.pending_text_relayout = true;
+                self.sys.update_frames_needed = 2;
+            }
 
             if changed {
                 self.sys.changes.rebuild_render_data = true;
@@ -2391,12 +2637,36 @@ impl Ui {
             if done {
                 self.sys.params_animation_targets.remove(slab_i);
                 self.sys.nodes[i].params_animation_target = None;
+                self.sys.nodes[i].animated_text_properties.clear();
+                self.sys.mark_animation_finished(i);
             } else {
                 // Keep the render loop going until the animation settles.
                 self.sys.changes.unfinished_animations = true;
             }
         }
     }
+
+    // Rebuild the text style with the animated text size and color, without the update code that normally sets it.
+    fn update_animated_text_style(&mut self, i: NodeI) {
+        let Some(text_i) = &self.sys.nodes[i].text_i else {
+            return;
+        };
+        let params = &self.sys.nodes[i].params;
+        with_arena(|arena| {
+            let text_properties = &self.sys.nodes[i].animated_text_properties;
+            let Some(properties) = text_style_overrides(params.text_style_flags, text_properties, params.text_size, params.text_color, arena) else {
+                return;
+            };
+            match text_i {
+                TextI::TextEdit(handle) => {
+                    self.sys.renderer.text.get_text_edit_mut(handle).set_style_property_overrides(&properties);
+                },
+                TextI::TextBox(handle) => {
+                    self.sys.renderer.text.get_text_box_mut(handle).set_style_property_overrides(&properties);
+                },
+            }
+        });
+    }
 }
 
 fn same_fill_variant(a: ColorFill2, b: ColorFill2) -> bool {
@@ -2407,59 +2677,51 @@ fn same_shape_variant(a: Shape, b: Shape) -> bool {
     std::mem::discriminant(&a) == std::mem::discriminant(&b)
 }
 
-fn animate_shape(current: &mut Shape, target: Shape, rate: f32, changed: &mut bool) -> bool {
-    let (new, done) = step_shape(*current, target, rate);
-    if new != *current {
-        *current = new;
-        *changed = true;
-    }
-    done
-}
 
-fn step_shape(current: Shape, target: Shape, rate: f32) -> (Shape, bool) {
+fn step_shape(current: Shape, target: Shape, step: CurveStep) -> (Shape, bool) {
     use Shape::*;
     match (current, target) {
         (
             Rectangle { rounded_corners: _, corner_radius: ca },
             Rectangle { rounded_corners, corner_radius: cb },
         ) => {
-            let (corner_radius, done) = step_f32(ca, cb, rate);
+            let (corner_radius, done) = step_f32(ca, cb, step);
             // rounded_corners is a discrete flag set; snap it to the target.
             (Rectangle { rounded_corners, corner_radius }, done)
         }
         (Ring { width: wa }, Ring { width: wb }) => {
-            let (width, done) = step_f32(wa, wb, rate);
+            let (width, done) = step_f32(wa, wb, step);
             (Ring { width }, done)
         }
         (
             Arc { start_angle: sa, end_angle: ea, width: wa },
             Arc { start_angle: sb, end_angle: eb, width: wb },
         ) => {
-            let (start_angle, d0) = step_f32(sa, sb, rate);
-            let (end_angle, d1) = step_f32(ea, eb, rate);
-            let (width, d2) = step_f32(wa, wb, rate);
+            let (start_angle, d0) = step_f32(sa, sb, step);
+            let (end_angle, d1) = step_f32(ea, eb, step);
+            let (width, d2) = step_f32(wa, wb, step);
             (Arc { start_angle, end_angle, width }, d0 && d1 && d2)
         }
         (
             Pie { start_angle: sa, end_angle: ea },
             Pie { start_angle: sb, end_angle: eb },
         ) => {
-            let (start_angle, d0) = step_f32(sa, sb, rate);
-            let (end_angle, d1) = step_f32(ea, eb, rate);
+            let (start_angle, d0) = step_f32(sa, sb, step);
+            let (end_angle, d1) = step_f32(ea, eb, step);
             (Pie { start_angle, end_angle }, d0 && d1)
         }
         (
             Segment { start: sa, end: ea, dash_length: da },
             Segment { start: sb, end: eb, dash_length: db },
         ) => {
-            let (sx, d0) = step_f32(sa.0, sb.0, rate);
-            let (sy, d1) = step_f32(sa.1, sb.1, rate);
-            let (ex, d2) = step_f32(ea.0, eb.0, rate);
-            let (ey, d3) = step_f32(ea.1, eb.1, rate);
+            let (sx, d0) = step_f32(sa.0, sb.0, step);
+            let (sy, d1) = step_f32(sa.1, sb.1, step);
+            let (ex, d2) = step_f32(ea.0, eb.0, step);
+            let (ey, d3) = step_f32(ea.1, eb.1, step);
             // dash_length can be None/Some; only interpolate when both are Some, else snap.
             let (dash_length, d4) = match (da, db) {
                 (Some(la), Some(lb)) => {
-                    let (l, d) = step_f32(la, lb, rate);
+                    let (l, d) = step_f32(la, lb, step);
                     (Some(l), d)
                 }
                 _ => (db, true),
@@ -2470,36 +2732,36 @@ fn step_shape(current: Shape, target: Shape, rate: f32) -> (Shape, bool) {
             Triangle { rotation: ra, width: wa },
             Triangle { rotation: rb, width: wb },
         ) => {
-            let (rotation, d0) = step_f32(ra, rb, rate);
-            let (width, d1) = step_f32(wa, wb, rate);
+            let (rotation, d0) = step_f32(ra, rb, step);
+            let (width, d1) = step_f32(wa, wb, step);
             (Triangle { rotation, width }, d0 && d1)
         }
         (
             SquareGrid { lattice_size: la, offset: oa, line_thickness: ta },
             SquareGrid { lattice_size: lb, offset: ob, line_thickness: tb },
         ) => {
-            let (lattice_size, d0) = step_f32(la, lb, rate);
-            let (ox, d1) = step_f32(oa.0, ob.0, rate);
-            let (oy, d2) = step_f32(oa.1, ob.1, rate);
-            let (line_thickness, d3) = step_f32(ta, tb, rate);
+            let (lattice_size, d0) = step_f32(la, lb, step);
+            let (ox, d1) = step_f32(oa.0, ob.0, step);
+            let (oy, d2) = step_f32(oa.1, ob.1, step);
+            let (line_thickness, d3) = step_f32(ta, tb, step);
             (SquareGrid { lattice_size, offset: (ox, oy), line_thickness }, d0 && d1 && d2 && d3)
         }
         (
             HexGrid { lattice_size: la, offset: oa, line_thickness: ta },
             HexGrid { lattice_size: lb, offset: ob, line_thickness: tb },
         ) => {
-            let (lattice_size, d0) = step_f32(la, lb, rate);
-            let (ox, d1) = step_f32(oa.0, ob.0, rate);
-            let (oy, d2) = step_f32(oa.1, ob.1, rate);
-            let (line_thickness, d3) = step_f32(ta, tb, rate);
+            let (lattice_size, d0) = step_f32(la, lb, step);
+            let (ox, d1) = step_f32(oa.0, ob.0, step);
+            let (oy, d2) = step_f32(oa.1, ob.1, step);
+            let (line_thickness, d3) = step_f32(ta, tb, step);
             (HexGrid { lattice_size, offset: (ox, oy), line_thickness }, d0 && d1 && d2 && d3)
         }
         (
             Hexagon { size: sa, rotation: ra },
             Hexagon { size: sb, rotation: rb },
         ) => {
-            let (size, d0) = step_f32(sa, sb, rate);
-            let (rotation, d1) = step_f32(ra, rb, rate);
+            let (size, d0) = step_f32(sa, sb, step);
+            let (rotation, d1) = step_f32(ra, rb, step);
             (Hexagon { size, rotation }, d0 && d1)
         }
         // Variants with no interpolable params (NoShape, Circle, HorizontalLine, VerticalLine),
@@ -2508,9 +2770,15 @@ fn step_shape(current: Shape, target: Shape, rate: f32) -> (Shape, bool) {
     }
 }
 
-// Step `current` toward `target`, set `changed` if it moved, and return whether it settled.
-fn animate_alpha(current: &mut f32, target: f32, rate: f32, changed: &mut bool) -> bool {
-    let (new, done) = step_f32(*current, target, rate);
+// Step `current` toward `target` with `step_fn`, set `changed` if it moved, and return whether it settled.
+fn animate_property<T: Copy + PartialEq>(
+    current: &mut T,
+    target: T,
+    step: CurveStep,
+    changed: &mut bool,
+    step_fn: impl Fn(T, T, CurveStep) -> (T, bool),
+) -> bool {
+    let (new, done) = step_fn(*current, target, step);
     if new != *current {
         *current = new;
         *changed = true;
@@ -2518,61 +2786,71 @@ fn animate_alpha(current: &mut f32, target: f32, rate: f32, changed: &mut bool)
     done
 }
 
-fn animate_color_fill(current: &mut ColorFill2, target: ColorFill2, rate: f32, changed: &mut bool) -> bool {
-    let (new, done) = step_color_fill(*current, target, rate);
-    if new != *current {
-        *current = new;
-        *changed = true;
+// Optional params are only interpolated when they're set on both sides. Otherwise they snap.
+fn step_option<T: Copy>(current: Option<T>, target: Option<T>, step: CurveStep, step_fn: impl Fn(T, T, CurveStep) -> (T, bool)) -> (Option<T>, bool) {
+    match (current, target) {
+        (Some(a), Some(b)) => {
+            let (value, done) = step_fn(a, b, step);
+            (Some(value), done)
+        }
+        _ => (target, true),
     }
-    done
 }
 
-// Step a scalar toward the target using the same shape of motion as the rectangle/position
+fn step_stroke(current: Stroke, target: Stroke, step: CurveStep) -> (Stroke, bool) {
+    let (width, d0) = step_f32(current.width, target.width, step);
+    let (color, d1) = step_color_fill(current.color, target.color, step);
+    let (dash_length, d2) = step_f32(current.dash_length, target.dash_length, step);
+    let (dash_offset, d3) = step_f32(current.dash_offset, target.dash_offset, step);
+    (Stroke { width, color, dash_length, dash_offset }, d0 && d1 && d2 && d3)
+}
+
+fn step_shadow(current: Shadow, target: Shadow, step: CurveStep) -> (Shadow, bool) {
+    let (blur, d0) = step_f32(current.blur, target.blur, step);
+    let (x, d1) = step_f32(current.offset.x, target.offset.x, step);
+    let (y, d2) = step_f32(current.offset.y, target.offset.y, step);
+    let (color, d3) = step_option(current.color, target.color, step, step_color);
+    (Shadow { blur, offset: Xy::new(x, y), color }, d0 && d1 && d2 && d3)
+}
+
+// Step a scalar toward the target. With the default exponential curve, this has the same shape of motion as the rectangle/position
 // animation in `layout.rs`: an exponential step (`dist * rate`) at the start, with a constant
 // minimum speed floor that makes the tail linear-ish, and a small snap threshold at the end.
-pub(crate) fn step_f32(current: f32, target: f32, rate: f32) -> (f32, bool) {
-    // Mirrors the rectangle animation: const_speed snap (≈3px) and min step (≈1px), here in
+pub(crate) fn step_f32(current: f32, target: f32, step: CurveStep) -> (f32, bool) {
+    // Mirrors the rectangle animation: const_speed snap (≈3px) and min speed (≈1px per frame at 60 fps), here in
     // normalized-ish units since shape params don't share a single pixel scale.
     const CONST_SPEED: f32 = 0.003;
-    const MIN_STEP: f32 = 0.005;
+    const MIN_SPEED: f32 = 0.3;
 
-    let diff = target - current;
-    let dist = diff.abs();
-    if dist < CONST_SPEED {
-        (target, true)
-    } else {
-        // exponential step at the start, but never slower than MIN_STEP, and never overshooting.
-        let step = (dist * rate).max(MIN_STEP).min(dist);
-        (current + step * diff.signum(), false)
-    }
+    return step.apply(current, target, MIN_SPEED, CONST_SPEED);
 }
 
-fn step_color(current: Color, target: Color, rate: f32) -> (Color, bool) {
-    let (r, d0) = step_f32(current.r, target.r, rate);
-    let (g, d1) = step_f32(current.g, target.g, rate);
-    let (b, d2) = step_f32(current.b, target.b, rate);
-    let (a, d3) = step_f32(current.a, target.a, rate);
+fn step_color(current: Color, target: Color, step: CurveStep) -> (Color, bool) {
+    let (r, d0) = step_f32(current.r, target.r, step);
+    let (g, d1) = step_f32(current.g, target.g, step);
+    let (b, d2) = step_f32(current.b, target.b, step);
+    let (a, d3) = step_f32(current.a, target.a, step);
     (Color::new(r, g, b, a), d0 && d1 && d2 && d3)
 }
 
-fn step_color_fill(current: ColorFill2, target: ColorFill2, rate: f32) -> (ColorFill2, bool) {
+fn step_color_fill(current: ColorFill2, target: ColorFill2, step: CurveStep) -> (ColorFill2, bool) {
     match (current, target) {
         (ColorFill2::Color(a), ColorFill2::Color(b)) => {
-            let (c, done) = step_color(a, b, rate);
+            let (c, done) = step_color(a, b, step);
             (ColorFill2::Color(c), done)
         }
         (ColorFill2::LinearGradient(a), ColorFill2::LinearGradient(b)) => {
-            let (start, d0) = step_color(a.color_start, b.color_start, rate);
-            let (end, d1) = step_color(a.color_end, b.color_end, rate);
-            let (angle, d2) = step_f32(a.angle_deg, b.angle_deg, rate);
+            let (start, d0) = step_color(a.color_start, b.color_start, step);
+            let (end, d1) = step_color(a.color_end, b.color_end, step);
+            let (angle, d2) = step_f32(a.angle_deg, b.angle_deg, step);
             (ColorFill2::LinearGradient(LinearGradient::new(start, end, angle)), d0 && d1 && d2)
         }
         (
             ColorFill2::RadialGradient { color_inner: ai, color_outer: ao },
             ColorFill2::RadialGradient { color_inner: bi, color_outer: bo },
         ) => {
-            let (inner, d0) = step_color(ai, bi, rate);
-            let (outer, d1) = step_color(ao, bo, rate);
+            let (inner, d0) = step_color(ai, bi, step);
+            let (outer, d1) = step_color(ao, bo, step);
             (ColorFill2::RadialGradient { color_inner: inner, color_outer: outer }, d0 && d1)
         }
         // Mismatched or non-interpolable variants (e.g. SharedGradient): snap to the target.
@@ -2585,6 +2863,7 @@ pub(crate) struct ParamsAnimation {
     target: Node<'static>,
     i: NodeI,
     id: Id,
+    progress: CurveProgress,
 }
 
 impl<'a> Node<'a> {
@@ -2620,7 +2899,8 @@ impl<'a> Node<'a> {
 
             image_options: self.image_options,
             text_properties: &[],
-            text_style_flags: TextStyleFlags::empty(),
+            keyframes: &[],
+            text_style_flags: self.text_style_flags,
             constant: self.constant,
 
             text: None,
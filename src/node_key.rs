@@ -22,6 +22,11 @@ pub struct NodeKey {
     temp: bool,
 }
 impl NodeKey {
+    // For nodes that are always added outside of any component, like the toasts.
+    pub(crate) const fn id_without_key_scope(&self) -> Id {
+        return self.id;
+    }
+
     pub(crate) fn id_with_key_scope(&self) -> Id {
         
         if let Some(key_scope_id) = thread_local::last_key_scope() && ! self.temp {
@@ -50,6 +50,13 @@ pub(crate) const NODE_ROOT_PARAMS: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(Size::Frac(1.0)),
@@ -79,11 +86,14 @@ pub(crate) const NODE_ROOT_PARAMS: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::GenericContainer,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -106,6 +116,13 @@ pub const DEFAULT: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(Size::Frac(1.0)),
@@ -135,11 +152,14 @@ pub const DEFAULT: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::GenericContainer,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -166,6 +186,13 @@ pub const V_STACK: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new(Size::FitContent, Size::FitContent),
@@ -195,11 +222,14 @@ pub const V_STACK: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::GenericContainer,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -226,6 +256,13 @@ pub const H_STACK: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new(Size::FitContent, Size::FitContent),
@@ -255,11 +292,14 @@ pub const H_STACK: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::GenericContainer,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -287,6 +327,13 @@ pub const V_SCROLL_STACK: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new(Size::FitContent, Size::Fill),
@@ -316,11 +363,14 @@ pub const V_SCROLL_STACK: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::ScrollView,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -349,6 +399,13 @@ pub const H_SCROLL_STACK: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new(Size::Fill, Size::FitContent),
@@ -378,11 +435,14 @@ pub const H_SCROLL_STACK: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::ScrollView,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -406,6 +466,13 @@ pub const MARGIN: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(Size::Frac(0.9)),
@@ -435,11 +502,14 @@ pub const MARGIN: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::GenericContainer,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -462,6 +532,13 @@ pub const ICON_BUTTON: Node = Node {
         click_animation: true,
         show_focus_indicator: true,
         focusable: true,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(Size::Pixels(40.0)),
@@ -491,11 +568,14 @@ pub const ICON_BUTTON: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Button,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -518,6 +598,13 @@ pub const IMAGE: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -547,11 +634,14 @@ pub const IMAGE: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Image,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -574,6 +664,13 @@ pub const IMAGE_BUTTON: Node = Node {
         click_animation: true,
         show_focus_indicator: true,
         focusable: true,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -603,11 +700,14 @@ pub const IMAGE_BUTTON: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Button,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -637,6 +737,13 @@ pub const BUTTON: Node = Node {
         click_animation: true,
         show_focus_indicator: true,
         focusable: true,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -666,11 +773,14 @@ pub const BUTTON: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Button,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -700,6 +810,13 @@ pub const LABEL: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -729,11 +846,14 @@ pub const LABEL: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Label,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -764,6 +884,13 @@ pub const MULTILINE_LABEL: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -793,11 +920,14 @@ pub const MULTILINE_LABEL: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Label,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -828,6 +958,13 @@ pub const TEXT: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -857,11 +994,14 @@ pub const TEXT: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Label,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -885,6 +1025,13 @@ pub const ICON: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(Size::Pixels(40.0)),
@@ -914,11 +1061,14 @@ pub const ICON: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Image,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -949,6 +1099,13 @@ pub const TEXT_EDIT: Node = Node {
         click_animation: true,
         show_focus_indicator: true,
         focusable: true,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -978,11 +1135,14 @@ pub const TEXT_EDIT: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::TextInput,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -1013,6 +1173,13 @@ pub const TEXT_EDIT_LINE: Node = Node {
         click_animation: true,
         show_focus_indicator: true,
         focusable: true,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -1042,11 +1209,14 @@ pub const TEXT_EDIT_LINE: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::TextInput,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -1077,6 +1247,13 @@ pub const TEXT_PARAGRAPH: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -1106,11 +1283,14 @@ pub const TEXT_PARAGRAPH: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Label,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -1134,6 +1314,13 @@ pub const PANEL: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -1163,11 +1350,14 @@ pub const PANEL: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::GenericContainer,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -1191,6 +1381,13 @@ pub const CONTAINER: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -1220,11 +1417,14 @@ pub const CONTAINER: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::GenericContainer,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -1284,6 +1484,13 @@ pub const CUSTOM_RENDERED_PANEL: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -1313,11 +1520,14 @@ pub const CUSTOM_RENDERED_PANEL: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Unknown,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -1341,6 +1551,13 @@ pub const SPACER: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(Fill),
@@ -1370,11 +1587,14 @@ pub const SPACER: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Unknown,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -1398,6 +1618,13 @@ pub const H_SPACER: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new(Size::Fill, Size::FitContent),
@@ -1427,11 +1654,14 @@ pub const H_SPACER: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Unknown,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -1455,6 +1685,13 @@ pub const V_SPACER: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new(Size::FitContent, Size::Fill),
@@ -1484,11 +1721,14 @@ pub const V_SPACER: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Unknown,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -1517,6 +1757,13 @@ pub const GRID: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new(Size::FitContent, Size::FitContent),
@@ -1546,11 +1793,14 @@ pub const GRID: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Grid,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
@@ -1579,6 +1829,13 @@ pub const H_LINE: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new(Size::Fill, Size::Pixels(0.0)),
@@ -1608,18 +1865,21 @@ pub const H_LINE: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::Unknown,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
 
 /// fake node used to keep track of components.
 pub(crate) const COMPONENT_ROOT: Node = Node {
-    animation: Animation { speed: 1.0, enter: EnterAnimation::None, exit: ExitAnimation::None, state_transition: StateTransition { animate_position: true, animate_properties: false } },
+    animation: Animation { speed: 1.0, curve: AnimationCurve::Exponential, stagger: 0.0, subtree_speed: 1.0, enter: EnterAnimation::None, exit: ExitAnimation::None, state_transition: StateTransition { animate_position: true, animate_properties: false } },
     key: None,
     children_layout: ChildrenLayout::Free,
     text_options: TextOptions::const_default(),
@@ -1636,6 +1896,13 @@ pub(crate) const COMPONENT_ROOT: Node = Node {
         click_animation: false,
         show_focus_indicator: true,
         focusable: false,
+        focus_trap: false,
+        focus_scope: false,
+        skip_tab_navigation: false,
+        tab_index: 0,
+        arrow_navigation: false,
+        uses_arrow_keys: false,
+        disabled: false,
     },
     layout: Layout {
         size: Xy::new_symm(FitContent),
@@ -1665,11 +1932,14 @@ pub(crate) const COMPONENT_ROOT: Node = Node {
     text_size: None,
     text_color: None,
     text_properties: &[],
+    keyframes: &[],
     text_style_flags: TextStyleFlags::empty(),
     accessibility: Accessibility {
         role: AccessKitRole::GenericContainer,
         selected: false,
         numeric_value: None,
+        expanded: None,
+        live: None,
         actions: AccessibilityActions::NONE,
     }
 };
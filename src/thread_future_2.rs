@@ -1,3 +1,5 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::mpsc::{channel, Receiver};
 use std::task::Poll;
 use std::thread;
@@ -7,12 +9,15 @@ use std::thread;
 /// Obtained by calling [`run_in_background()`].
 /// 
 /// Use [`Self::poll()`] to check for the result.
-pub struct ThreadFuture<T: Send + 'static>(Receiver<T>);
+pub struct ThreadFuture<T: Send + 'static> {
+    receiver: Receiver<T>,
+    progress: Arc<AtomicU32>,
+}
 
 impl<T: Send + 'static> ThreadFuture<T> {
     /// Returns [`Poll::Pending`] if the value is not ready, or [`Poll::Ready(value)`](`std::task::Poll::Ready`) if the background function has finished executing. `value` is the result of the background function.
     pub fn poll(&self) -> Poll<T> {
-        match self.0.try_recv() {
+        match self.receiver.try_recv() {
             Ok(value) => Poll::Ready(value),
             Err(_) => Poll::Pending,
         }
@@ -20,7 +25,13 @@ impl<T: Send + 'static> ThreadFuture<T> {
     
     /// Blocks until the value is ready and returns it.
     pub fn wait(self) -> T {
-        self.0.recv().expect("background thread panicked")
+        self.receiver.recv().expect("background thread panicked")
+    }
+
+    /// Returns the last fraction reported through [`Progress::set()`], or `None` if the background function didn't report any progress.
+    pub fn progress(&self) -> Option<f32> {
+        let progress = f32::from_bits(self.progress.load(Ordering::Relaxed));
+        return (! progress.is_nan()).then_some(progress);
     }
 }
 
@@ -44,5 +55,39 @@ pub fn run_in_background<T: Send + 'static>(
         waker();
     });
 
-    ThreadFuture(rx)
+    ThreadFuture { receiver: rx, progress: Arc::new(AtomicU32::new(f32::NAN.to_bits())) }
+}
+
+/// A handle passed to the function in [`run_in_background_with_progress()`], to report how far along it is.
+pub struct Progress {
+    value: Arc<AtomicU32>,
+    waker: Arc<dyn Fn() + Send + Sync + 'static>,
+}
+
+impl Progress {
+    /// Report the progress as a fraction from 0.0 to 1.0, and call the waker.
+    pub fn set(&self, fraction: f32) {
+        self.value.store(fraction.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+        (self.waker)();
+    }
+}
+
+/// Like [`run_in_background()`], but `function` can report its progress through a [`Progress`] handle, which can then be read with [`ThreadFuture::progress()`].
+/// 
+/// `waker` is called on every progress report, as well as when the function completes.
+pub fn run_in_background_with_progress<T: Send + 'static>(
+    function: impl FnOnce(&Progress) -> T + Send + 'static,
+    waker: impl Fn() + Send + Sync + 'static,
+) -> ThreadFuture<T> {
+    let (tx, rx) = channel();
+    let value = Arc::new(AtomicU32::new(f32::NAN.to_bits()));
+    let progress = Progress { value: Arc::clone(&value), waker: Arc::new(waker) };
+
+    thread::spawn(move || {
+        let result = function(&progress);
+        let _ = tx.send(result);
+        (progress.waker)();
+    });
+
+    ThreadFuture { receiver: rx, progress: value }
 }
\ No newline at end of file
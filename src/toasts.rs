@@ -0,0 +1,205 @@
+use crate as keru;
+use keru::*;
+use keru::node_library::*;
+use std::time::{Duration, Instant};
+use winit::event::MouseButton;
+
+/// How important a toast is. It decides the toast's accent color, and how urgently screen readers announce it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// Identifies a toast created with [`Ui::notify()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+pub(crate) struct Toast {
+    id: ToastId,
+    message: String,
+    level: ToastLevel,
+    actions: Vec<&'static str>,
+    // Only counts down while the toast isn't hovered.
+    remaining: Duration,
+    last_update: Option<Instant>,
+    was_hovered: bool,
+}
+
+#[derive(Default)]
+pub(crate) struct Toasts {
+    toasts: Vec<Toast>,
+    next_id: u64,
+}
+
+const MAX_TOASTS: usize = 5;
+const TOAST_WIDTH: f32 = 320.0;
+
+#[node_key] const TOAST: NodeKey;
+#[node_key] const TOAST_CLOSE: NodeKey;
+#[node_key] const TOAST_ACTION: NodeKey;
+
+impl Ui {
+    /// Show a toast notification with `message` in the bottom right corner.
+    ///
+    /// The toast is dismissed after `duration`, or when its close button is clicked. The countdown is paused while the toast is hovered. Use [`Duration::MAX`] for a toast that stays until it's closed.
+    ///
+    /// Toasts are announced to screen readers as they appear.
+    ///
+    /// The toasts are added to the tree at the end of the frame, in [`Ui::finish_frame()`], on top of everything else.
+    pub fn notify(&mut self, message: &str, level: ToastLevel, duration: Duration) -> ToastId {
+        return self.notify_with_actions(message, level, duration, &[]);
+    }
+
+    /// Like [`Ui::notify()`], but the toast also has a button for each of `actions`.
+    ///
+    /// Use [`Ui::toast_action_clicked()`] to know when one of them is clicked. Clicking an action also dismisses the toast.
+    pub fn notify_with_actions(&mut self, message: &str, level: ToastLevel, duration: Duration, actions: &[&'static str]) -> ToastId {
+        let toasts = &mut self.sys.toasts;
+        let id = ToastId(toasts.next_id);
+        toasts.next_id += 1;
+
+        toasts.toasts.push(Toast {
+            id,
+            message: message.to_string(),
+            level,
+            actions: actions.to_vec(),
+            remaining: duration,
+            last_update: None,
+            was_hovered: false,
+        });
+        if toasts.toasts.len() > MAX_TOASTS {
+            toasts.toasts.remove(0);
+        }
+
+        // In case it was called outside of an update.
+        self.push_external_event();
+        return id;
+    }
+
+    /// If one of the actions of the toast `id` was clicked this frame, returns its index in the `actions` passed to [`Ui::notify_with_actions()`].
+    pub fn toast_action_clicked(&self, id: ToastId) -> Option<usize> {
+        let toast = self.sys.toasts.toasts.iter().find(|t| t.id == id)?;
+        return (0..toast.actions.len()).find(|&a| {
+            let key = TOAST_ACTION.sibling((id, a));
+            self.sys.check_clicked(key.id_without_key_scope(), MouseButton::Left)
+        });
+    }
+
+    /// Dismiss the toast `id`, if it's still shown.
+    pub fn dismiss_toast(&mut self, id: ToastId) {
+        self.sys.toasts.toasts.retain(|t| t.id != id);
+    }
+
+    fn schedule_toast_wakeup(&mut self, duration: Duration) {
+        // Without a window there's nothing to wake up. The toast will still be dismissed on the next update after it expires.
+        if self.sys.window_ref.is_some() && duration != Duration::MAX {
+            self.schedule_wakeup(duration);
+        }
+    }
+
+    pub(crate) fn add_toasts(&mut self) {
+        if self.sys.toasts.toasts.is_empty() {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut wakeups = Vec::new();
+
+        let mut toasts = std::mem::take(&mut self.sys.toasts.toasts);
+        toasts.retain_mut(|toast| {
+            let id = toast.id;
+            let clicked = |key: NodeKey| self.sys.check_clicked(key.id_without_key_scope(), MouseButton::Left);
+            if clicked(TOAST_CLOSE.sibling(id)) || (0..toast.actions.len()).any(|a| clicked(TOAST_ACTION.sibling((id, a)))) {
+                return false;
+            }
+
+            let hovered = self.sys.check_hovered(TOAST.sibling(id).id_without_key_scope());
+            if let Some(last_update) = toast.last_update && ! hovered && toast.remaining != Duration::MAX {
+                toast.remaining = toast.remaining.saturating_sub(now.duration_since(last_update));
+            }
+
+            // The countdown starts on the toast's first frame, and starts again when the cursor leaves.
+            let countdown_started = toast.last_update.is_none() || toast.was_hovered;
+            if countdown_started && ! hovered && ! toast.remaining.is_zero() {
+                wakeups.push(toast.remaining);
+            }
+            toast.last_update = Some(now);
+            toast.was_hovered = hovered;
+
+            return ! toast.remaining.is_zero();
+        });
+        for duration in wakeups {
+            self.schedule_toast_wakeup(duration);
+        }
+
+        let stack = V_STACK
+            .position(Pos::End, Pos::End)
+            .size_symm(Size::FitContent)
+            .padding(15.0)
+            .stack_arrange(Arrange::End)
+            .z_index(2000.0)
+            .absorbs_clicks(false);
+
+        self.add(stack).nest(|| {
+            for toast in &toasts {
+                self.add_toast(toast);
+            }
+        });
+
+        // Toasts created while adding the nodes would go after the current ones.
+        toasts.append(&mut self.sys.toasts.toasts);
+        self.sys.toasts.toasts = toasts;
+    }
+
+    fn add_toast(&mut self, toast: &Toast) {
+        let id = toast.id;
+        let (accent, live) = match toast.level {
+            ToastLevel::Info => (self.theme().primary, AccessKitLive::Polite),
+            ToastLevel::Success => (ColorFill2::Color(Color::KERU_GREEN), AccessKitLive::Polite),
+            ToastLevel::Warning => (ColorFill2::Color(Color::new(0.95, 0.65, 0.15, 1.0)), AccessKitLive::Assertive),
+            ToastLevel::Error => (self.theme().error, AccessKitLive::Assertive),
+        };
+
+        let panel = PANEL
+            .size_x(Size::Pixels(TOAST_WIDTH))
+            .size_y(Size::FitContent)
+            .stack(Axis::X, Arrange::Start, 10.0)
+            .fill(self.theme().surface)
+            .stroke(1.0)
+            .stroke_fill(self.theme().border)
+            .slide_from_right()
+            .animate_position(true)
+            .sense_hover_enter_or_exit(true)
+            .accessibility_role(AccessKitRole::Alert)
+            .accessibility_live(live)
+            .key(TOAST.sibling(id));
+
+        let stripe = PANEL
+            .size_x(Size::Pixels(4.0))
+            .size_y(Size::Fill)
+            .padding(0.0)
+            .fill(accent);
+
+        let close = BUTTON
+            .static_text("×")
+            .key(TOAST_CLOSE.sibling(id));
+
+        self.add(panel).nest(|| {
+            self.add(stripe);
+            self.add(V_STACK.size_x(Size::Fill).stack_spacing(8.0)).nest(|| {
+                self.add(TEXT.text(&toast.message).size_x(Size::Fill));
+                if ! toast.actions.is_empty() {
+                    self.add(H_STACK.stack_spacing(5.0)).nest(|| {
+                        for (a, action) in toast.actions.iter().enumerate() {
+                            self.add(BUTTON.static_text(*action).key(TOAST_ACTION.sibling((id, a))));
+                        }
+                    });
+                }
+            });
+            self.add(close);
+        });
+    }
+}
@@ -41,6 +41,7 @@ impl Ui {
     {
         let key = node.key_or_anon_key();
         let (i, _id) = self.add_or_update_node(key);
+        let node = self.apply_disabled_state(i, node);
         self.set_params(i, &node);
         self.set_params_text(i, &node);
 
@@ -546,6 +547,7 @@ impl Ui {
     /// This function must be called once per frame, after calling [`Ui::begin_frame()`] and running your ui declaration code.
     pub fn finish_frame(&mut self) {
         log::trace!("Finished Ui update");
+        self.add_toasts();
         // pop the root node
         thread_local::pop_parent(self.sys.unique_id);
 
@@ -760,6 +762,10 @@ impl Ui {
             self.sys.user_state.remove(&id);
         }
 
+        if let Some(anim_i) = self.sys.nodes[i].keyframe_animation.take() {
+            self.sys.keyframe_animations.remove(anim_i.get() as usize - 1);
+        }
+
         self.sys.nodes.remove(id);
     }
 
@@ -1132,6 +1138,7 @@ impl Ui {
     {
         let key = node.key_or_anon_key();
         let (i, _id) = self.add_or_update_node(key);
+        let node = self.apply_disabled_state(i, node);
         self.set_params(i, &node);
         self.set_params_text(i, &node);
 
@@ -1252,13 +1259,12 @@ impl System {
     pub(crate) fn link_node_to_parent(&mut self, new_node_i: NodeI, parent_i: NodeI, _depth: usize, sibling_cursor: SiblingCursor) {
         assert!(new_node_i != parent_i, "Keru: Internal error: tried to add a node as child of itself ({}). This shouldn't be possible.", self.nodes[new_node_i].debug_name());
 
-        // If parent changed, convert local_animated_rect to the new parent's coordinate space using screen-space positions from the previous frame.
+        // If parent changed, remember the screen-space rect from the previous frame, so that the node can animate from there to its new place.
+        // It can only be converted to the new parent's coordinate space after the new parent's own rect is known: the new parent might have just been added.
         let old_parent = self.nodes[new_node_i].parent;
         let is_new_node = self.nodes[new_node_i].frame_added == self.current_frame;
         if !is_new_node && old_parent != parent_i {
-            let screen_pos = self.nodes[new_node_i].real_rect;
-            let new_parent_offset = self.nodes[parent_i].real_rect.top_left();
-            self.nodes[new_node_i].local_animated_rect = screen_pos - new_parent_offset;
+            self.nodes[new_node_i].reparented_from = Some(self.nodes[new_node_i].real_rect);
         }
 
         // Add new child
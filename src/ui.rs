@@ -78,6 +78,7 @@ pub(crate) struct System {
     pub nodes: Nodes,
 
     pub params_animation_targets: Slab<ParamsAnimation>,
+    pub keyframe_animations: Slab<KeyframeAnimation>,
 
     pub custom_render_commands: Vec<RenderCommand>,
 
@@ -85,6 +86,13 @@ pub(crate) struct System {
 
     pub global_animation_speed: f32,
     pub disable_animations_on_resize: bool,
+    pub reduced_motion: bool,
+
+    pub animation_clock: AnimationClock,
+    pub animation_timer: FrameTimer,
+    pub scroll_timer: FrameTimer,
+    // Seconds since the previous animation step, measured at the start of rebuild_render_data.
+    pub animation_dt: f32,
 
     pub t: f32, // time at the end of the last rendered frame, in seconds since the Ui creation
 
@@ -145,6 +153,8 @@ pub(crate) struct System {
     pub window_ref: Option<Weak<Window>>,
     pub scheduled_wakeup: Option<ScheduledWakeupHandle>,
 
+    pub toasts: Toasts,
+
     pub device: wgpu::Device,
     pub queue: wgpu::Queue,
 
@@ -267,10 +277,16 @@ impl Ui {
             sys: System {
                 nodes,
                 params_animation_targets: slab::Slab::with_capacity(6),
+                keyframe_animations: slab::Slab::with_capacity(6),
                 custom_render_commands: Vec::with_capacity(50),
                 t: 0.0,
                 global_animation_speed: 1.0,
                 disable_animations_on_resize: true,
+                reduced_motion: false,
+                animation_clock: AnimationClock::Real,
+                animation_timer: FrameTimer::default(),
+                scroll_timer: FrameTimer::default(),
+                animation_dt: 0.0,
                 unique_id: INSTANCE_COUNTER.fetch_add(1, Ordering::Relaxed),
                 z_cursor: 0.0,
                 last_linked_text_box_node: None,
@@ -319,6 +335,8 @@ impl Ui {
                 window_ref: None,
                 scheduled_wakeup: None,
 
+                toasts: Toasts::default(),
+
                 device: device.clone(),
                 queue: queue.clone(),
 
@@ -380,11 +398,35 @@ impl Ui {
         self.sys.global_animation_speed
     }
 
+    /// Set where animations get their frame time from.
+    ///
+    /// By default, animations measure the real time between frames, so they take the same time at any frame rate. Use [`AnimationClock::Fixed`] to make them deterministic.
+    pub fn set_animation_clock(&mut self, clock: AnimationClock) {
+        self.sys.animation_clock = clock;
+    }
+
     /// Set whether animations should be disabled during window resize.
     pub fn set_disable_animations_on_resize(&mut self, disable: bool) {
         self.sys.disable_animations_on_resize = disable;
     }
 
+    /// Set reduced motion mode, for users who are sensitive to motion.
+    ///
+    /// In reduced motion mode, slide, grow and shrink animations are replaced by fades, position transitions and smooth scrolling happen instantly, keyframe tracks that move or scale the node jump to their last value, and the built-in components replace their moving parts with pulses.
+    ///
+    /// Fades, property transitions and the other keyframe tracks still play. To disable all animations in a subtree, use [`Node::disable_subtree_animations()`].
+    pub fn set_reduced_motion(&mut self, reduced_motion: bool) {
+        if self.sys.reduced_motion != reduced_motion {
+            self.sys.changes.full_relayout = true;
+        }
+        self.sys.reduced_motion = reduced_motion;
+    }
+
+    /// Get whether reduced motion mode is active. See [`Ui::set_reduced_motion()`].
+    pub fn reduced_motion(&self) -> bool {
+        self.sys.reduced_motion
+    }
+
     /// Set inspect mode. When inspect mode is active, all nodes will be shown, including stacks and containers. 
     pub fn set_inspect_mode(&mut self, inspect_mode: bool) {
         if self.inspect_mode() != inspect_mode {
@@ -157,6 +157,47 @@ impl<'a> UiNode<'a> {
         return size - padding;
     }
 
+    /// Returns how far the node's content is scrolled, in screen pixels. Positive values mean that the content was scrolled down or right.
+    /// 
+    /// This function will return the value from last frame.
+    pub fn scroll_offset(&self) -> Xy<f32> {
+        let scroll = self.node().scroll;
+        let logical_size = self.sys().logical_size();
+        return Xy::new(-scroll.x * logical_size.x, -scroll.y * logical_size.y);
+    }
+
+    /// Returns `true` if any part of the node is on screen and not clipped away by its parents.
+    ///
+    /// This function will return the value from last frame.
+    pub fn is_on_screen(&self) -> bool {
+        let node = self.node();
+        if node.currently_hidden {
+            return false;
+        }
+        let rect = node.real_rect;
+        let clip = node.clip_rect;
+        return rect[X][0] < clip[X][1] && rect[X][1] > clip[X][0]
+            && rect[Y][0] < clip[Y][1] && rect[Y][1] > clip[Y][0];
+    }
+
+    /// Returns `true` if any of the node's animations is still going: position, enter and exit animations, property transitions or keyframes.
+    ///
+    /// Looping keyframes never finish.
+    ///
+    /// This function will return the value from last frame.
+    pub fn is_animating(&self) -> bool {
+        let node = self.node();
+        let sys = self.sys();
+        let keyframes_running = node.keyframe_animation
+            .is_some_and(|anim_i| sys.keyframe_animations[anim_i.get() as usize - 1].is_running());
+        return node.animating_layout || node.params_animation_target.is_some() || keyframes_running;
+    }
+
+    /// Returns `true` if one of the node's animations finished since the last update.
+    pub fn animation_finished(&self) -> bool {
+        return self.node().animation_finished_frame == self.sys().current_frame;
+    }
+
     /// Returns the center of the node's rectangle, in screen pixels.
     /// 
     /// Since the size and position of nodes is only determined after the layout pass at the end of the frame, 
@@ -440,6 +481,10 @@ impl System {
     #[cfg(debug_assertions)]
     pub(crate) fn check_node_sense(&self, i: NodeI, sense: Sense, fn_name: &'static str, sense_add_fn_name: &'static str) -> bool {
         let node = &self.nodes[i];
+        // Disabled nodes lose their senses on purpose, no need to warn.
+        if node.params.interact.disabled {
+            return false;
+        }
         if !node.params.interact.senses.contains(sense) {
             eprintln!(
                 "Keru: Debug mode check: \"{}\" was called for node {}, but the node doesn't have the {:?} sense. In release mode, this event will be silently ignored! You can add the sense to the node's Node with the \"{}\" function.",